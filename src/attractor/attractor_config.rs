@@ -1,6 +1,9 @@
 use crate::reachability::ReachabilityConfig;
+use crate::trimming::TrimSetting;
 use biodivine_lib_param_bn::VariableId;
-use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
 use std::collections::BTreeSet;
 
 /// A configuration object for attractor detection algorithms.
@@ -26,6 +29,96 @@ pub struct AttractorConfig {
     /// Note: In the future, this could be replaced by a global "symbolic size" cancellation
     /// trigger, but this will likely rely on direct support from the BDD library.
     pub max_symbolic_size: usize,
+    /// Trim the remaining universe between pivot selections of the Xie–Beerel algorithm
+    /// (default: [`TrimSetting::None`]).
+    ///
+    /// Trimming is applied every time a basin is removed from the remaining universe. Fixed
+    /// points that are removed by trimming are retained, hence the set of detected attractors
+    /// is not affected by this setting; only the number of required pivots can change.
+    pub trim_between_pivots: TrimSetting,
+    /// If set, only attractors that intersect this set of candidate states are reported
+    /// (default: `None`).
+    ///
+    /// The exploration of the state space is not affected; only the reported attractors
+    /// are filtered. For parametrized networks, an attractor is only reported for colors
+    /// where it intersects the candidate set. Once the remaining universe no longer
+    /// intersects the candidate set, the search terminates early.
+    pub required_intersection: Option<GraphColoredVertices>,
+    /// Stop the search once the given number of attractors has been reported
+    /// (default: `usize::MAX`).
+    ///
+    /// For parametrized networks, the limit applies to the total number of reported
+    /// sets, not to the number of attractors of individual colors.
+    pub max_attractors: usize,
+    /// Restrict the initial universe to its greatest forward trap before the search
+    /// (default: false).
+    ///
+    /// Attractor detection is only correct if the initial universe is a forward trap. If enabled,
+    /// the states that can escape the universe are removed before the exploration starts. If
+    /// the universe is already a forward trap, this has no effect.
+    pub auto_trap: bool,
+    /// Select fixed points as pivots of the Xie–Beerel algorithm before any other states
+    /// (default: false).
+    ///
+    /// If enabled, all fixed points of the initial universe are computed before the search
+    /// starts, and they are reported (together with their basins) before any other attractors.
+    /// This can speed up the search for models dominated by steady states, since a fixed-point
+    /// pivot requires no attractor iteration. The set of reported attractors is not affected;
+    /// only their order can change.
+    pub sink_candidates_first: bool,
+    /// An optional label of this run which is used as a prefix of all log messages
+    /// (default: `None`). This is useful to distinguish logs of concurrent runs.
+    pub label: Option<String>,
+}
+
+/// The graph-independent settings of an [`AttractorConfig`].
+///
+/// Unlike [`AttractorConfig`], this object does not contain the [`SymbolicAsyncGraph`] (or any
+/// symbolic sets), so it can be stored in a configuration file and later re-attached to
+/// a graph using [`AttractorSettings::rehydrate`]. The
+/// [`AttractorConfig::required_intersection`] is not included and is `None` after rehydration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttractorSettings {
+    /// See [`AttractorConfig::active_variables`].
+    pub active_variables: BTreeSet<VariableId>,
+    /// See [`AttractorConfig::max_symbolic_size`].
+    pub max_symbolic_size: usize,
+    /// See [`AttractorConfig::trim_between_pivots`].
+    pub trim_between_pivots: TrimSetting,
+    /// See [`AttractorConfig::max_attractors`].
+    pub max_attractors: usize,
+    /// See [`AttractorConfig::auto_trap`].
+    pub auto_trap: bool,
+    /// See [`AttractorConfig::sink_candidates_first`].
+    pub sink_candidates_first: bool,
+    /// See [`AttractorConfig::label`].
+    pub label: Option<String>,
+}
+
+impl AttractorSettings {
+    /// Create an [`AttractorConfig`] for the given `graph` using these settings.
+    ///
+    /// The `graph` should be the same as (or compatible with) the graph of the
+    /// original config, since the settings refer to its variables.
+    pub fn rehydrate(self, graph: SymbolicAsyncGraph) -> AttractorConfig {
+        AttractorConfig {
+            active_variables: self.active_variables,
+            max_symbolic_size: self.max_symbolic_size,
+            trim_between_pivots: self.trim_between_pivots,
+            max_attractors: self.max_attractors,
+            auto_trap: self.auto_trap,
+            sink_candidates_first: self.sink_candidates_first,
+            label: self.label,
+            ..AttractorConfig::new(graph)
+        }
+    }
+}
+
+impl From<&AttractorConfig> for AttractorSettings {
+    fn from(value: &AttractorConfig) -> Self {
+        value.settings()
+    }
 }
 
 impl From<SymbolicAsyncGraph> for AttractorConfig {
@@ -43,10 +136,10 @@ impl From<&SymbolicAsyncGraph> for AttractorConfig {
 impl From<&AttractorConfig> for ReachabilityConfig {
     fn from(value: &AttractorConfig) -> Self {
         ReachabilityConfig {
-            graph: value.graph.clone(),
             active_variables: value.active_variables.clone(),
-            max_iterations: usize::MAX,
             max_symbolic_size: value.max_symbolic_size,
+            label: value.label.clone(),
+            ..ReachabilityConfig::new(value.graph.clone())
         }
     }
 }
@@ -57,7 +150,78 @@ impl AttractorConfig {
         AttractorConfig {
             active_variables: graph.variables().collect(),
             max_symbolic_size: usize::MAX,
+            trim_between_pivots: TrimSetting::None,
+            required_intersection: None,
+            max_attractors: usize::MAX,
+            auto_trap: false,
+            sink_candidates_first: false,
+            label: None,
             graph,
         }
     }
+
+    /// The graph-independent settings of this config (see [`AttractorSettings`]).
+    pub fn settings(&self) -> AttractorSettings {
+        AttractorSettings {
+            active_variables: self.active_variables.clone(),
+            max_symbolic_size: self.max_symbolic_size,
+            trim_between_pivots: self.trim_between_pivots,
+            max_attractors: self.max_attractors,
+            auto_trap: self.auto_trap,
+            sink_candidates_first: self.sink_candidates_first,
+            label: self.label.clone(),
+        }
+    }
+
+    /// Update the [`AttractorConfig::trim_between_pivots`] setting.
+    pub fn trim_between_pivots(mut self, setting: TrimSetting) -> AttractorConfig {
+        self.trim_between_pivots = setting;
+        self
+    }
+
+    /// Update the [`AttractorConfig::required_intersection`] setting.
+    pub fn require_intersection(mut self, candidates: GraphColoredVertices) -> AttractorConfig {
+        self.required_intersection = Some(candidates);
+        self
+    }
+
+    /// Update the [`AttractorConfig::max_attractors`] setting.
+    pub fn max_attractors(mut self, limit: usize) -> AttractorConfig {
+        self.max_attractors = limit;
+        self
+    }
+
+    /// Restrict the [`AttractorConfig::graph`] to the given `colors` (typically a single parameter
+    /// valuation, e.g., obtained using [`GraphColors::pick_singleton`]).
+    ///
+    /// For a single color, the algorithms then behave exactly as on the non-parametrized
+    /// network given by this valuation. Note that the initial set of the algorithm should be
+    /// created from the restricted graph (e.g., using `XieBeerelState::from(&config.graph)`), since states
+    /// of the other colors have no transitions in the restricted graph.
+    pub fn pin_color(mut self, colors: &GraphColors) -> AttractorConfig {
+        let pinned = self
+            .graph
+            .mk_unit_colored_vertices()
+            .intersect_colors(colors);
+        self.graph = self.graph.restrict(&pinned);
+        self
+    }
+
+    /// Update the [`AttractorConfig::auto_trap`] setting.
+    pub fn auto_trap(mut self, auto_trap: bool) -> AttractorConfig {
+        self.auto_trap = auto_trap;
+        self
+    }
+
+    /// Update the [`AttractorConfig::sink_candidates_first`] setting.
+    pub fn sink_candidates_first(mut self, sink_candidates_first: bool) -> AttractorConfig {
+        self.sink_candidates_first = sink_candidates_first;
+        self
+    }
+
+    /// Update the [`AttractorConfig::label`] setting.
+    pub fn label(mut self, label: impl Into<String>) -> AttractorConfig {
+        self.label = Some(label.into());
+        self
+    }
 }
@@ -1,8 +1,8 @@
 use crate::attractor::AttractorConfig;
-use crate::log_set;
 use crate::reachability::{
     ReachabilityConfig, ReachabilityStep, SaturationPredecessors, SaturationSuccessors,
 };
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
@@ -92,24 +92,18 @@ impl Step {
 
 impl ItgrState {
     pub fn new(graph: &SymbolicAsyncGraph, universe: &GraphColoredVertices) -> Self {
-        ItgrState {
-            remaining_set: universe.clone(),
-            remaining_reachability: graph.restrict(universe).into(),
-            to_discard: None,
-            reductions: graph
-                .variables()
-                .map(|it| {
-                    let step = Step::Forward(StepForward {
-                        forward: graph.var_can_post(it, universe),
-                    });
-                    (it, step)
-                })
-                .collect(),
-        }
+        let variables = graph.variables().collect::<Vec<_>>();
+        Self::new_with_variables(graph, universe, &variables)
     }
 
     /// Create a new [`ItgrState`] using a restricted set of variables (i.e., only the given
     /// variables will be reduced).
+    ///
+    /// Only the transitions of the listed variables are used as seeds of transition-guided
+    /// reductions, but reachability still uses all variables of the `graph`. Hence, the reduced
+    /// universe still contains all attractors of the original `universe`. Fewer candidate
+    /// variables mean less work per reduction (and fewer reductions overall), but typically
+    /// also a larger (less reduced) result.
     pub fn new_with_variables(
         graph: &SymbolicAsyncGraph,
         universe: &GraphColoredVertices,
@@ -131,6 +125,12 @@ impl ItgrState {
         }
     }
 
+    /// The current set of remaining states (after the computation is finished, this is
+    /// the result of the reduction).
+    pub(crate) fn remaining_set(&self) -> &GraphColoredVertices {
+        &self.remaining_set
+    }
+
     /// Returns an iterator over variables that are still considered "active" (i.e., not eliminated)
     /// in the current state of reduction.
     ///
@@ -159,13 +159,14 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                     .graph
                     .var_can_post_within(var, &state.remaining_set);
                 if can_post.is_empty() {
-                    debug!("Variable {} eliminated.", var);
+                    debug!("{}Variable {} eliminated.", LogLabel(&context.label), var);
                     state.remaining_reachability.active_variables.remove(&var);
                 }
             }
 
             info!(
-                "Remaining set reduced ({}). Active tasks: {}",
+                "{}Remaining set reduced ({}). Active tasks: {}",
+                LogLabel(&context.label),
                 log_set(&state.remaining_set),
                 state.reductions.len()
             );
@@ -202,7 +203,8 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
 
                         state.reductions.pop();
                         info!(
-                            "[{}] Forward set done. Spawning extended component computation ({}).",
+                            "{}[{}] Forward set done. Spawning extended component computation ({}).",
+                            LogLabel(&context.label),
                             var,
                             log_set(&forward)
                         );
@@ -224,7 +226,8 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                         let basin_candidates = state.remaining_set.minus(&forward);
                         if !basin_candidates.is_empty() {
                             info!(
-                                "[{}] Spawning forward-basin reduction ({})",
+                                "{}[{}] Spawning forward-basin reduction ({})",
+                                LogLabel(&context.label),
                                 var,
                                 log_set(&basin_candidates)
                             );
@@ -240,7 +243,12 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                         Err(Suspended)
                     } else {
                         x.forward = x.forward.union(&post);
-                        debug!("[{}] Forward increased ({}).", var, log_set(&x.forward));
+                        debug!(
+                            "{}[{}] Forward increased ({}).",
+                            LogLabel(&context.label),
+                            var,
+                            log_set(&x.forward)
+                        );
                         Err(Suspended)
                     }
                 }
@@ -252,7 +260,8 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                         // Backward reachability is done. If the bottom set is not empty,
                         // we can try to remove its basin.
                         info!(
-                            "[{}] Extended component done ({})",
+                            "{}[{}] Extended component done ({})",
+                            LogLabel(&context.label),
                             var,
                             log_set(&x.extended_component)
                         );
@@ -273,7 +282,8 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
 
                         if !bottom.is_empty() || !is_var_closed.is_empty() {
                             info!(
-                                "[{}] Spawning bottom-basin reduction ({})",
+                                "{}[{}] Spawning bottom-basin reduction ({})",
+                                LogLabel(&context.label),
                                 var,
                                 log_set(&bottom)
                             );
@@ -290,7 +300,8 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                     } else {
                         x.extended_component = x.extended_component.union(&pre);
                         debug!(
-                            "[{}] Extended component increased ({}).",
+                            "{}[{}] Extended component increased ({}).",
+                            LogLabel(&context.label),
                             var,
                             log_set(&x.extended_component)
                         );
@@ -301,23 +312,38 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                     let pre =
                         SaturationPredecessors::step(&state.remaining_reachability, &x.basin)?;
                     if pre.is_empty() {
-                        info!("[{}] Forward basin done ({})", var, log_set(&x.basin));
+                        info!(
+                            "{}[{}] Forward basin done ({})",
+                            LogLabel(&context.label),
+                            var,
+                            log_set(&x.basin)
+                        );
                         let to_discard = x.basin.minus(&x.forward);
                         if !to_discard.is_empty() {
                             info!(
-                                "[{}] Discarding with forward basin ({})",
+                                "{}[{}] Discarding with forward basin ({})",
+                                LogLabel(&context.label),
                                 var,
                                 log_set(&to_discard)
                             );
                             state.to_discard = Some(to_discard);
                         } else {
-                            info!("[{}] Cannot discard anything using forward basin", var);
+                            info!(
+                                "{}[{}] Cannot discard anything using forward basin",
+                                LogLabel(&context.label),
+                                var
+                            );
                         }
                         state.reductions.pop();
                         Err(Suspended)
                     } else {
                         x.basin = x.basin.union(&pre);
-                        debug!("[{}] Forward basin increased ({}).", var, log_set(&x.basin));
+                        debug!(
+                            "{}[{}] Forward basin increased ({}).",
+                            LogLabel(&context.label),
+                            var,
+                            log_set(&x.basin)
+                        );
                         Err(Suspended)
                     }
                 }
@@ -326,7 +352,8 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                         SaturationPredecessors::step(&state.remaining_reachability, &x.basin)?;
                     if pre.is_empty() {
                         info!(
-                            "[{}] Bottom basin done ({}) with universe ({})",
+                            "{}[{}] Bottom basin done ({}) with universe ({})",
+                            LogLabel(&context.label),
                             var,
                             log_set(&x.basin),
                             log_set(&state.remaining_set)
@@ -334,19 +361,29 @@ impl ComputationStep<AttractorConfig, ItgrState, GraphColoredVertices> for ItgrS
                         let to_discard = x.basin.minus(&x.bottom);
                         if !to_discard.is_empty() {
                             info!(
-                                "[{}] Discarding with bottom basin ({})",
+                                "{}[{}] Discarding with bottom basin ({})",
+                                LogLabel(&context.label),
                                 var,
                                 log_set(&to_discard)
                             );
                             state.to_discard = Some(to_discard);
                         } else {
-                            info!("[{}] Cannot discard anything using bottom basin", var);
+                            info!(
+                                "{}[{}] Cannot discard anything using bottom basin",
+                                LogLabel(&context.label),
+                                var
+                            );
                         }
                         state.reductions.pop();
                         Err(Suspended)
                     } else {
                         x.basin = x.basin.union(&pre);
-                        debug!("[{}] Bottom basin increased ({}).", var, log_set(&x.basin));
+                        debug!(
+                            "{}[{}] Bottom basin increased ({}).",
+                            LogLabel(&context.label),
+                            var,
+                            log_set(&x.basin)
+                        );
                         Err(Suspended)
                     }
                 }
@@ -12,6 +12,13 @@
 //!   shrink the explored state space and identify variables that are irrelevant in the remaining
 //!   part of the graph.
 //!
+//! If good attractor candidates are already known (e.g., fixed points), they can be verified
+//! directly using [`attractors_from_candidates`].
+//!
+//! To exchange the results with other tools, [`attractor_report`] summarizes the attractors
+//! of a graph (kind, size, and a representative state). With the `serde` feature, the report
+//! can be also obtained as JSON using `attractor_report_json`.
+//!
 //! # Typical usage
 //!
 //! For large models, it is often useful to run ITGR first to reduce the universe, then run
@@ -52,21 +59,306 @@
 
 mod attractor_config;
 mod itgr;
+mod report;
 mod xie_beerel;
 
 #[cfg(test)]
 mod tests;
 
-pub use attractor_config::AttractorConfig;
-use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
-use computation_process::{Computation, Generator};
+use crate::reachability::{
+    BackwardReachability, BackwardReachabilityBfs, ForwardReachability, can_reach,
+};
+use crate::trapping::ForwardTrap;
+pub use attractor_config::{AttractorConfig, AttractorSettings};
+use biodivine_lib_param_bn::VariableId;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
+use cancel_this::{Cancellable, is_cancelled};
+use computation_process::{Algorithm, Computable, Computation, Generator, Stateful};
 pub use itgr::{ItgrState, ItgrStep};
-pub use xie_beerel::{XieBeerelState, XieBeerelStep};
+use num_bigint::BigUint;
+#[cfg(feature = "serde")]
+pub use report::attractor_report_json;
+pub use report::{AttractorKind, AttractorReport, AttractorSummary, attractor_report};
+use std::collections::{HashMap, HashSet};
+pub use xie_beerel::{XieBeerelState, XieBeerelStats, XieBeerelStep};
 
 /// Enumerate attractors using the Xie–Beerel algorithm.
 pub type XieBeerelAttractors =
     Generator<AttractorConfig, XieBeerelState, GraphColoredVertices, XieBeerelStep>;
 
+/// Enumerate attractors using the Xie–Beerel algorithm, together with their basins.
+///
+/// Each item is a pair `(attractor, basin)`, where `basin` is the set of states of the explored
+/// universe that can reach the attractor and do not belong to the basin of a previously
+/// reported attractor (restricted to the colors of the attractor). As such, the basins of the
+/// reported attractors are pairwise disjoint and, once all attractors are reported, they cover
+/// the explored universe for each color. This also includes the states that were removed by
+/// trimming ([`AttractorConfig::trim_between_pivots`]) or due to a pivot that did not lead to
+/// an attractor in some colors (such states are assigned to the first reported attractor
+/// that they can reach).
+///
+/// If the search is stopped early (e.g., due to [`AttractorConfig::max_attractors`]), or some
+/// attractors are not reported (due to [`AttractorConfig::required_intersection`]), the
+/// basins do not have to cover the whole universe.
+pub type XieBeerelAttractorBasins = Generator<
+    AttractorConfig,
+    XieBeerelState,
+    (GraphColoredVertices, GraphColoredVertices),
+    XieBeerelStep,
+>;
+
 /// Reduce the universe using Interleaved Transition-Guided Reduction (ITGR).
 pub type InterleavedTransitionGuidedReduction =
     Computation<AttractorConfig, ItgrState, GraphColoredVertices, ItgrStep>;
+
+/// Compute the set of transient states of the `graph`, i.e., the states that do not belong
+/// to any attractor.
+///
+/// This is the complement of the union of all attractors (computed using
+/// [`XieBeerelAttractors`]). For parametrized networks, this is evaluated for each color
+/// separately.
+pub fn transient_states(graph: &SymbolicAsyncGraph) -> Cancellable<GraphColoredVertices> {
+    let mut attractors = graph.mk_empty_colored_vertices();
+    for attractor in XieBeerelAttractors::configure(graph, XieBeerelState::from(graph)) {
+        attractors = attractors.union(&attractor?);
+    }
+    Ok(graph.mk_unit_colored_vertices().minus(&attractors))
+}
+
+/// Compute the longest transient length of the `graph`, i.e., the maximal number of
+/// transitions that a state needs to reach an attractor (the maximum over all states of
+/// their shortest distance to an attractor).
+///
+/// This is computed as the number of layers of the BFS backward reachability
+/// ([`BackwardReachabilityBfs`]) from the union of all attractors. For parametrized networks,
+/// the result is the maximum over all colors.
+pub fn max_transient_length(graph: &SymbolicAsyncGraph) -> Cancellable<usize> {
+    let attractors = graph
+        .mk_unit_colored_vertices()
+        .minus(&transient_states(graph)?);
+    let mut layers = BackwardReachabilityBfs::configure(graph, attractors);
+    layers.compute()?;
+    // The last iteration does not discover any new states.
+    Ok(layers.state().iteration - 1)
+}
+
+/// Enumerate the attractors of the `graph` (using [`XieBeerelAttractors`]) together with
+/// the sizes of their weak basins.
+///
+/// The weak basin of an attractor is the set of states that can reach it (computed using
+/// [`can_reach`]). Since a state can reach multiple attractors, the weak basins can overlap and
+/// their sizes need not sum to the size of the state space. For parametrized networks,
+/// the size is the number of state-color pairs (see
+/// [`GraphColoredVertices::exact_cardinality`]).
+///
+/// Note that for a fixed color, two distinct attractors never have identical weak basins:
+/// each basin contains its own attractor, but an attractor cannot reach any other attractor.
+/// Weak basins can therefore only overlap, never coincide.
+pub fn attractors_with_basin_sizes(
+    graph: &SymbolicAsyncGraph,
+) -> Cancellable<Vec<(GraphColoredVertices, BigUint)>> {
+    let mut result = Vec::new();
+    for attractor in XieBeerelAttractors::configure(graph, XieBeerelState::from(graph)) {
+        let attractor = attractor?;
+        let basin = can_reach(graph, &attractor)?;
+        result.push((attractor, basin.exact_cardinality()));
+    }
+    Ok(result)
+}
+
+/// Compute the indices of the `attractors` that can be reached from the `from` set.
+///
+/// This runs [`ForwardReachability`] from `from`
+/// and returns the (increasing) indices of the attractors that intersect the reachable set.
+/// For parametrized networks, reachability is evaluated for each color separately, and an
+/// attractor is included if it is reachable for at least one color. The answer can therefore
+/// differ across colors; to query a single color, restrict `from` to that color first.
+pub fn reachable_attractors(
+    graph: &SymbolicAsyncGraph,
+    attractors: &[GraphColoredVertices],
+    from: &GraphColoredVertices,
+) -> Cancellable<Vec<usize>> {
+    let reachable = ForwardReachability::run(graph, from.clone())?;
+    Ok(attractors
+        .iter()
+        .enumerate()
+        .filter(|(_, attractor)| !attractor.intersect(&reachable).is_empty())
+        .map(|(i, _)| i)
+        .collect())
+}
+
+/// Compute the attractors of the `graph` that intersect the given `candidates` without
+/// a full decomposition of the state space.
+///
+/// For each candidate pivot (one state per color), the forward reachable set is computed
+/// and then verified to be a bottom SCC, i.e., the pivot must be reachable from every state
+/// of its forward reachable set. Candidates that do not belong to a bottom SCC (in a particular
+/// color) are discarded, even though they can still reach some attractor. As such, this is much
+/// faster than [`XieBeerelAttractors`] when the candidates are good (e.g., the fixed points and
+/// states of suspected cycles), but slow if many candidates are transient states with large
+/// forward reachable sets.
+///
+/// Each attractor is reported once, even if it contains multiple candidates. For parametrized
+/// networks, the attractors are computed per color, and one reported set can cover multiple
+/// colors (like the results of [`XieBeerelAttractors`]).
+pub fn attractors_from_candidates(
+    graph: &SymbolicAsyncGraph,
+    candidates: &GraphColoredVertices,
+) -> Cancellable<Vec<GraphColoredVertices>> {
+    let mut result = Vec::new();
+    let mut remaining = candidates.clone();
+    while !remaining.is_empty() {
+        is_cancelled!()?;
+        let pivot = remaining.pick_vertex();
+        let forward = ForwardReachability::run(graph, pivot.clone())?;
+        let backward = BackwardReachability::run(graph.restrict(&forward), pivot.clone())?;
+        let not_bottom = forward.minus(&backward).colors();
+        let attractor = forward.minus_colors(&not_bottom);
+        remaining = remaining.minus(&pivot).minus(&attractor);
+        if !attractor.is_empty() {
+            result.push(attractor);
+        }
+    }
+    Ok(result)
+}
+
+/// Compute the "decision points" of the basin of the `target` attractor, i.e., the states
+/// in its weak basin that have a successor outside its strong basin.
+///
+/// The weak basin is the set of states that can reach `target` (see [`can_reach`]) and the strong
+/// basin is the greatest forward trap within the weak basin (see [`ForwardTrap`]), i.e., the states
+/// that reach `target` along every path. The result is a subset of the weak basin minus the strong
+/// basin (the strong basin has no successors outside of itself). From these states, a single
+/// transition can move further away from the inevitable reachability of `target`, so avoiding
+/// them is a (coarse) approximation of a control set. For parametrized networks, this is
+/// evaluated for each color separately.
+pub fn basin_boundary(
+    graph: &SymbolicAsyncGraph,
+    target: &GraphColoredVertices,
+) -> Cancellable<GraphColoredVertices> {
+    let weak_basin = can_reach(graph, target)?;
+    let strong_basin = ForwardTrap::run(graph, weak_basin.clone())?;
+    let outside = graph.mk_unit_colored_vertices().minus(&strong_basin);
+    Ok(graph.pre(&outside).intersect(&weak_basin))
+}
+
+/// Compute the variables that change their value within the `attractor` (its "oscillating
+/// core"), i.e., the variables for which both `true` and `false` occur in the `attractor` set.
+///
+/// The remaining variables are constant in the whole attractor. For a fixed point (see
+/// [`AttractorKind::FixedPoint`]), the result is empty. For parametrized networks, a variable
+/// is included if it oscillates for at least one color.
+pub fn oscillating_variables(
+    graph: &SymbolicAsyncGraph,
+    attractor: &GraphColoredVertices,
+) -> HashSet<VariableId> {
+    graph
+        .variables()
+        .filter(|var| {
+            let is_true = attractor.intersect(&graph.fix_network_variable(*var, true));
+            let is_false = attractor.intersect(&graph.fix_network_variable(*var, false));
+            !is_true.colors().intersect(&is_false.colors()).is_empty()
+        })
+        .collect()
+}
+
+/// The "attractor structure" of a single color: the sizes (number of states) of all its
+/// attractors, sorted in increasing order (see [`attractor_signatures`]).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AttractorSignature {
+    /// The attractor sizes in increasing order. The length is the number of attractors.
+    pub sizes: Vec<BigUint>,
+}
+
+/// Partition the colors of the `graph` by their [`AttractorSignature`], i.e., by the number
+/// and sizes of their attractors.
+///
+/// The attractors are computed using [`XieBeerelAttractors`]. The colors are then partitioned
+/// symbolically: each attractor splits the existing classes by its colors and by its size in
+/// each color. Hence, the colors are only considered separately where the attractors actually
+/// differ.
+pub fn attractor_signatures(
+    graph: &SymbolicAsyncGraph,
+) -> Cancellable<HashMap<AttractorSignature, GraphColors>> {
+    let attractors = XieBeerelAttractors::configure(graph, XieBeerelState::from(graph))
+        .collect::<Cancellable<Vec<_>>>()?;
+
+    // The sizes in each class are kept sorted, such that classes with the same signature
+    // are merged immediately.
+    let mut classes: HashMap<Vec<BigUint>, GraphColors> =
+        HashMap::from([(Vec::new(), graph.mk_unit_colors())]);
+    for attractor in &attractors {
+        let attractor_colors = attractor.colors();
+        let mut split: HashMap<Vec<BigUint>, GraphColors> = HashMap::new();
+        for (sizes, colors) in classes {
+            let mut insert = |sizes: Vec<BigUint>, colors: GraphColors| {
+                let colors = match split.remove(&sizes) {
+                    Some(existing) => existing.union(&colors),
+                    None => colors,
+                };
+                split.insert(sizes, colors);
+            };
+
+            let without = colors.minus(&attractor_colors);
+            if !without.is_empty() {
+                insert(sizes.clone(), without);
+            }
+            let with = colors.intersect(&attractor_colors);
+            if with.is_empty() {
+                continue;
+            }
+            for (size, size_colors) in colors_by_size(graph, &attractor.intersect_colors(&with))? {
+                let mut sizes = sizes.clone();
+                let position = sizes.partition_point(|it| *it <= size);
+                sizes.insert(position, size);
+                insert(sizes, size_colors);
+            }
+        }
+        classes = split;
+    }
+
+    Ok(classes
+        .into_iter()
+        .map(|(sizes, colors)| (AttractorSignature { sizes }, colors))
+        .collect())
+}
+
+/// Partition the colors of the `set` by the number of its states in each color.
+///
+/// If the set contains the same vertices in all its colors, no splitting is needed. Otherwise,
+/// the colors are split by the presence of a vertex that is missing in some of them, and the
+/// process is repeated in both halves (i.e., it only goes down to individual colors if the
+/// vertices differ in every color).
+fn colors_by_size(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Cancellable<Vec<(BigUint, GraphColors)>> {
+    let mut result: Vec<(BigUint, GraphColors)> = Vec::new();
+    let mut stack = vec![set.clone()];
+    while let Some(set) = stack.pop() {
+        is_cancelled!()?;
+        let colors = set.colors();
+        let vertices = set.vertices();
+        let missing = graph
+            .mk_unit_colored_vertices()
+            .intersect_colors(&colors)
+            .intersect_vertices(&vertices)
+            .minus(&set);
+        if missing.is_empty() {
+            let size = vertices.exact_cardinality();
+            match result.iter_mut().find(|(it, _)| *it == size) {
+                Some((_, existing)) => *existing = existing.union(&colors),
+                None => result.push((size, colors)),
+            }
+        } else {
+            let vertex = missing.vertices().pick_singleton();
+            let with_vertex = set.intersect_vertices(&vertex).colors();
+            stack.push(set.intersect_colors(&with_vertex));
+            stack.push(set.minus_colors(&with_vertex));
+        }
+    }
+    Ok(result)
+}
@@ -0,0 +1,97 @@
+use crate::attractor::{XieBeerelAttractors, XieBeerelState};
+use crate::enumeration::pick_smallest_valuation;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use cancel_this::{Cancellable, is_cancelled};
+use computation_process::Stateful;
+use std::collections::BTreeMap;
+
+/// The kind of attractor reported by [`attractor_report`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AttractorKind {
+    /// The attractor is a single state without successors.
+    FixedPoint,
+    /// The attractor contains multiple states.
+    Cyclic,
+}
+
+/// A summary of one attractor (or one group of attractors, see [`attractor_report`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttractorSummary {
+    /// Whether the attractor is a fixed point or a cyclic attractor.
+    pub kind: AttractorKind,
+    /// The number of distinct states of the attractor (as a decimal string, since the
+    /// number can exceed the range of JSON numbers).
+    pub states: String,
+    /// The number of colors exhibiting the attractor (as a decimal string).
+    pub colors: String,
+    /// The lexicographically smallest state of the attractor, given as variable names
+    /// and their values.
+    pub representative: BTreeMap<String, bool>,
+}
+
+/// A machine-readable summary of all attractors of a graph (see [`attractor_report`]).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttractorReport {
+    /// The summaries of individual attractors, in the order in which they were found.
+    pub attractors: Vec<AttractorSummary>,
+}
+
+/// Enumerate the attractors of the `graph` (using [`XieBeerelAttractors`]) and summarize them
+/// as an [`AttractorReport`].
+///
+/// For parametrized networks, one attractor set can contain fixed points for some colors and
+/// cyclic attractors for other colors. Such a set is reported as two separate summaries,
+/// one for each kind. The state counts are then taken over all colors of the summary.
+pub fn attractor_report(graph: &SymbolicAsyncGraph) -> Cancellable<AttractorReport> {
+    let mut report = AttractorReport::default();
+    for attractor in XieBeerelAttractors::configure(graph, XieBeerelState::from(graph)) {
+        let attractor = attractor?;
+        let mut has_successor = graph.mk_empty_colored_vertices();
+        for var in graph.variables() {
+            is_cancelled!()?;
+            has_successor = has_successor.union(&graph.var_can_post(var, &attractor));
+        }
+        let fixed_points = attractor.minus(&has_successor);
+        let cyclic = attractor.minus_colors(&fixed_points.colors());
+        for (kind, set) in [
+            (AttractorKind::FixedPoint, fixed_points),
+            (AttractorKind::Cyclic, cyclic),
+        ] {
+            if !set.is_empty() {
+                report.attractors.push(summarize(graph, kind, &set));
+            }
+        }
+    }
+    Ok(report)
+}
+
+/// Same as [`attractor_report`], but the report is serialized as a JSON document.
+#[cfg(feature = "serde")]
+pub fn attractor_report_json(graph: &SymbolicAsyncGraph) -> Cancellable<String> {
+    let report = attractor_report(graph)?;
+    // The report only contains strings, booleans, and unit variants, hence it always serializes.
+    Ok(serde_json::to_string_pretty(&report).expect("Attractor report is serializable"))
+}
+
+fn summarize(
+    graph: &SymbolicAsyncGraph,
+    kind: AttractorKind,
+    set: &GraphColoredVertices,
+) -> AttractorSummary {
+    let context = graph.symbolic_context();
+    let representative = graph
+        .variables()
+        .map(|var| context.get_network_variable_name(var))
+        .zip(pick_smallest_valuation(graph, set))
+        .collect();
+    AttractorSummary {
+        kind,
+        states: set.vertices().exact_cardinality().to_string(),
+        colors: set.colors().exact_cardinality().to_string(),
+        representative,
+    }
+}
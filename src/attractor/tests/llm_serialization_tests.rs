@@ -5,12 +5,13 @@
 //! to produce the same results as uninterrupted computations.
 
 use crate::attractor::{
-    AttractorConfig, InterleavedTransitionGuidedReduction, ItgrState, XieBeerelAttractors,
-    XieBeerelState,
+    AttractorConfig, AttractorReport, AttractorSettings, InterleavedTransitionGuidedReduction,
+    ItgrState, XieBeerelAttractors, XieBeerelState, attractor_report, attractor_report_json,
 };
 use crate::test_utils::llm_example_network::create_test_network;
 use crate::test_utils::llm_example_network::sets::{ATTRACTOR_1, ATTRACTOR_2};
 use crate::test_utils::{init_logger, mk_states};
+use crate::trimming::TrimSetting;
 use cancel_this::Cancellable;
 use computation_process::{Algorithm, Computable, Stateful};
 use serde_json;
@@ -332,3 +333,39 @@ fn test_xie_beerel_serialization_roundtrip() -> Cancellable<()> {
 fn test_xie_beerel_serialization_multiple_steps() -> Cancellable<()> {
     test_xie_beerel_serialization_multiple_steps_impl()
 }
+
+#[test]
+fn test_attractor_settings_roundtrip() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = AttractorConfig::new(graph.clone())
+        .trim_between_pivots(TrimSetting::Both)
+        .max_attractors(1)
+        .label("batch_job");
+
+    let serialized = serde_json::to_string(&config.settings()).expect("Serialization failed");
+    assert!(!serialized.contains("graph"));
+    let settings: AttractorSettings =
+        serde_json::from_str(&serialized).expect("Deserialization failed");
+    assert_eq!(settings, config.settings());
+
+    let config = settings.rehydrate(graph.clone());
+    assert_eq!(config.max_attractors, 1);
+    assert_eq!(config.trim_between_pivots, TrimSetting::Both);
+    let attractors = XieBeerelAttractors::configure(config, XieBeerelState::from(&graph))
+        .collect::<Cancellable<Vec<_>>>()?;
+    assert_eq!(attractors.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_attractor_report_json() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let json = attractor_report_json(&graph)?;
+    assert!(json.contains("FixedPoint"));
+    assert!(json.contains("Cyclic"));
+    let report: AttractorReport = serde_json::from_str(&json).expect("Deserialization failed");
+    assert_eq!(report, attractor_report(&graph)?);
+    Ok(())
+}
@@ -3,18 +3,32 @@
 //! See `llm_example_network.rs` for the complete documentation of the test network structure.
 
 use crate::attractor::{
-    AttractorConfig, InterleavedTransitionGuidedReduction, ItgrState, XieBeerelAttractors,
-    XieBeerelState,
+    AttractorConfig, AttractorKind, AttractorSignature, InterleavedTransitionGuidedReduction,
+    ItgrState, XieBeerelAttractorBasins, XieBeerelAttractors, XieBeerelState, XieBeerelStats,
+    XieBeerelStep, attractor_report, attractor_signatures, attractors_from_candidates,
+    attractors_with_basin_sizes, basin_boundary, max_transient_length, oscillating_variables,
+    reachable_attractors, transient_states,
 };
-use crate::test_utils::llm_example_network::create_test_network;
-use crate::test_utils::llm_example_network::sets::{ATTRACTOR_1, ATTRACTOR_2};
-use crate::test_utils::llm_transition_builder::from_transitions;
-use crate::test_utils::{init_logger, mk_states};
+use crate::build::from_transitions;
+use crate::reachability::BackwardReachability;
+use crate::test_utils::llm_example_network::sets::{
+    ATTRACTOR_1, ATTRACTOR_2, CAN_REACH_ATTR1, CAN_REACH_ATTR2, WEAK_BASIN,
+};
+use crate::test_utils::llm_example_network::states::{
+    S000, S001, S010, S011, S100, S101, S110, S111,
+};
+use crate::test_utils::llm_example_network::{
+    create_parameterized_test_network, create_test_network,
+};
+use crate::test_utils::{collect_state_numbers, init_logger, mk_states};
+use crate::trimming::TrimSetting;
+use biodivine_lib_param_bn::BooleanNetwork;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
 use cancel_this::Cancellable;
-use computation_process::{Computable, Stateful};
-use std::collections::BTreeSet;
+use computation_process::{Algorithm, Computable, GeneratorStep, Stateful};
+use num_bigint::BigUint;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 
 /// Verify that the attractors found match the expected attractors exactly.
 /// This handles the fact that attractors can be returned in arbitrary order.
@@ -416,6 +430,43 @@ fn test_cycle_with_transient_states_impl(use_itgr: bool) -> Cancellable<()> {
     Ok(())
 }
 
+/// Test that trimming between pivots does not change the detected attractors, including
+/// fixed points, which are trivially removed by trimming.
+fn test_trim_between_pivots_impl(use_itgr: bool) -> Cancellable<()> {
+    init_logger();
+    // The same network as in `test_two_disjoint_cycles_impl`.
+    let transitions = vec![
+        (0b000, 0b100),
+        (0b100, 0b000),
+        (0b011, 0b111),
+        (0b111, 0b011),
+        (0b001, 0b000),
+        (0b010, 0b000),
+        (0b101, 0b111),
+        (0b110, 0b111),
+    ];
+
+    let bn = from_transitions(3, &transitions).expect("Failed to create network");
+    let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+    let example = create_test_network();
+
+    for setting in [
+        TrimSetting::None,
+        TrimSetting::Both,
+        TrimSetting::Sources,
+        TrimSetting::Sinks,
+    ] {
+        let config = AttractorConfig::new(graph.clone()).trim_between_pivots(setting);
+        let attractors = run_xie_beerel(config, use_itgr)?;
+        verify_attractors(&graph, attractors, &[&[0b000, 0b100], &[0b011, 0b111]]);
+
+        let config = AttractorConfig::new(example.clone()).trim_between_pivots(setting);
+        let attractors = run_xie_beerel(config, use_itgr)?;
+        verify_attractors(&example, attractors, &[ATTRACTOR_1, ATTRACTOR_2]);
+    }
+    Ok(())
+}
+
 // ========== Tests ==========
 
 #[test]
@@ -557,3 +608,596 @@ fn test_cycle_with_transient_states() -> Cancellable<()> {
 fn test_cycle_with_transient_states_with_itgr() -> Cancellable<()> {
     test_cycle_with_transient_states_impl(true)
 }
+
+#[test]
+fn test_trim_between_pivots() -> Cancellable<()> {
+    test_trim_between_pivots_impl(false)
+}
+
+#[test]
+fn test_trim_between_pivots_with_itgr() -> Cancellable<()> {
+    test_trim_between_pivots_impl(true)
+}
+
+#[test]
+fn test_trim_between_pivots_active_variables() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    // Only x2 can change: 001 -> 000, 011 -> 010, and 110 <-> 111.
+    let vars = graph.variables().collect::<Vec<_>>();
+    let mut config = AttractorConfig::new(graph.clone());
+    config.active_variables = BTreeSet::from([vars[2]]);
+    for trim in [
+        TrimSetting::None,
+        TrimSetting::Both,
+        TrimSetting::Sources,
+        TrimSetting::Sinks,
+    ] {
+        let config = config.clone().trim_between_pivots(trim);
+        let mut generator = XieBeerelAttractors::configure(config, XieBeerelState::from(&graph));
+        let mut attractors = Vec::new();
+        for result in &mut generator {
+            attractors.push(result?);
+        }
+        verify_attractors(
+            &graph,
+            attractors,
+            &[&[S000], &[S010], &[S100], &[S101], ATTRACTOR_2],
+        );
+        assert_eq!(generator.state().check_coverage(), Ok(()));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_xie_beerel_stats() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let initial_state = XieBeerelState::from(&graph);
+    let mut generator =
+        XieBeerelAttractors::configure(AttractorConfig::new(graph.clone()), initial_state);
+    assert_eq!(generator.state().stats(), &XieBeerelStats::default());
+
+    let mut attractors = Vec::new();
+    for result in &mut generator {
+        attractors.push(result?);
+    }
+    verify_attractors(&graph, attractors, &[ATTRACTOR_1, ATTRACTOR_2]);
+
+    let stats = generator.state().stats();
+    assert_eq!(stats.attractors, 2);
+    assert!(stats.pivots >= 2);
+    assert!(stats.backward_steps >= stats.pivots);
+    assert!(stats.peak_symbolic_size > 0);
+    Ok(())
+}
+
+#[test]
+fn test_require_intersection() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    let candidates = mk_states(&graph, &[S110]);
+    let config = AttractorConfig::new(graph.clone()).require_intersection(candidates);
+    let attractors = run_xie_beerel(config, false)?;
+    verify_attractors(&graph, attractors, &[ATTRACTOR_2]);
+
+    // Transient states never intersect an attractor.
+    let candidates = mk_states(&graph, &[S011, S100]);
+    let config = AttractorConfig::new(graph.clone()).require_intersection(candidates);
+    let attractors = run_xie_beerel(config, true)?;
+    verify_attractors(&graph, attractors, &[]);
+    Ok(())
+}
+
+#[test]
+fn test_max_attractors() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = AttractorConfig::new(graph.clone()).max_attractors(1);
+    let mut generator = XieBeerelAttractors::configure(config, XieBeerelState::from(&graph));
+
+    let mut attractors = Vec::new();
+    for result in &mut generator {
+        attractors.push(result?);
+    }
+    assert_eq!(attractors.len(), 1);
+    assert_eq!(generator.state().stats().attractors, 1);
+    assert!(generator.next().is_none());
+
+    let config = AttractorConfig::new(graph.clone()).max_attractors(0);
+    assert!(run_xie_beerel(config, false)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_xie_beerel_coverage() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let unit = graph.mk_unit_colored_vertices();
+    let configs = [
+        AttractorConfig::new(graph.clone()),
+        AttractorConfig::new(graph.clone()).trim_between_pivots(TrimSetting::Both),
+    ];
+    for config in configs {
+        let mut generator = XieBeerelAttractors::configure(config, XieBeerelState::from(&graph));
+        assert_eq!(generator.state().check_coverage(), Err(unit.clone()));
+        for result in &mut generator {
+            result?;
+        }
+        assert_eq!(generator.state().check_coverage(), Ok(()));
+    }
+
+    // If the search is stopped early, the unexplored states are not covered.
+    let config = AttractorConfig::new(graph.clone()).max_attractors(1);
+    let mut generator = XieBeerelAttractors::configure(config, XieBeerelState::from(&graph));
+    let attractor = generator.next().unwrap()?;
+    let uncovered = generator.state().check_coverage().unwrap_err();
+    assert!(!uncovered.is_empty());
+    assert!(uncovered.intersect(&attractor).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_attractors_from_candidates() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    // 011 is transient, so it is discarded. 110 and 111 belong to the same attractor.
+    let candidates = mk_states(&graph, &[S000, S011, S110, S111]);
+    let attractors = attractors_from_candidates(&graph, &candidates)?;
+    verify_attractors(&graph, attractors, &[ATTRACTOR_1, ATTRACTOR_2]);
+
+    let candidates = mk_states(&graph, &[S001, S011, S101]);
+    assert!(attractors_from_candidates(&graph, &candidates)?.is_empty());
+
+    let all = graph.mk_unit_colored_vertices();
+    let attractors = attractors_from_candidates(&graph, &all)?;
+    verify_attractors(&graph, attractors, &[ATTRACTOR_1, ATTRACTOR_2]);
+    Ok(())
+}
+
+#[test]
+fn test_basin_boundary() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    // 011 and 100 can reach both attractors, and they both have a successor outside the
+    // strong basin of either attractor.
+    let expected = mk_states(&graph, WEAK_BASIN);
+    assert_eq!(
+        basin_boundary(&graph, &mk_states(&graph, ATTRACTOR_1))?,
+        expected
+    );
+    assert_eq!(
+        basin_boundary(&graph, &mk_states(&graph, ATTRACTOR_2))?,
+        expected
+    );
+
+    // Every state reaches the only attractor, hence there are no decision points.
+    let bn = from_transitions(2, &[(0b00, 0b01), (0b01, 0b11), (0b11, 0b10), (0b10, 0b11)])
+        .expect("Failed to create network");
+    let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+    let attractor = mk_states(&graph, &[0b10, 0b11]);
+    assert!(basin_boundary(&graph, &attractor)?.is_empty());
+
+    // 001 can reach both 000 and 011, and 101 only leads to 001. Hence, 101 is a decision
+    // point even though none of its successors is in the strong basin {000}.
+    let bn = from_transitions(3, &[(0b001, 0b000), (0b001, 0b011), (0b101, 0b001)])
+        .expect("Failed to create network");
+    let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+    assert_eq!(
+        basin_boundary(&graph, &mk_states(&graph, &[0b000]))?,
+        mk_states(&graph, &[0b001, 0b101])
+    );
+    Ok(())
+}
+
+#[test]
+fn test_transient_states() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let transient = transient_states(&graph)?;
+    let mut attractors = ATTRACTOR_1.to_vec();
+    attractors.extend_from_slice(ATTRACTOR_2);
+    let expected = graph
+        .mk_unit_colored_vertices()
+        .minus(&mk_states(&graph, &attractors));
+    assert_eq!(transient, expected);
+    Ok(())
+}
+
+#[test]
+fn test_attractor_basins() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let initial_state = XieBeerelState::from(&graph);
+    let generator =
+        XieBeerelAttractorBasins::configure(AttractorConfig::new(graph.clone()), initial_state);
+
+    let mut attractors = Vec::new();
+    let mut covered = graph.mk_empty_colored_vertices();
+    for result in generator {
+        let (attractor, basin) = result?;
+        assert!(attractor.is_subset(&basin));
+        assert!(basin.intersect(&covered).is_empty());
+        covered = covered.union(&basin);
+        attractors.push(attractor);
+    }
+    verify_attractors(&graph, attractors, &[ATTRACTOR_1, ATTRACTOR_2]);
+    Ok(())
+}
+
+#[test]
+fn test_attractor_basins_partition_universe() -> Cancellable<()> {
+    init_logger();
+    for graph in [create_test_network(), create_parameterized_test_network()] {
+        let default = AttractorConfig::new(graph.clone());
+        let configs = [
+            default.clone(),
+            default.clone().trim_between_pivots(TrimSetting::Both),
+            default.clone().trim_between_pivots(TrimSetting::Sources),
+            default.trim_between_pivots(TrimSetting::Sinks),
+        ];
+        for config in configs {
+            let generator =
+                XieBeerelAttractorBasins::configure(config, XieBeerelState::from(&graph));
+            let mut covered = graph.mk_empty_colored_vertices();
+            for result in generator {
+                let (attractor, basin) = result?;
+                assert!(attractor.is_subset(&basin));
+                assert!(basin.intersect(&covered).is_empty());
+                // Every basin state can reach the attractor (in the same color).
+                let reach = BackwardReachability::run(&graph, attractor.clone())?;
+                assert!(basin.is_subset(&reach));
+                covered = covered.union(&basin);
+            }
+            // For each color, the basins cover the whole universe.
+            assert_eq!(covered, graph.mk_unit_colored_vertices());
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_auto_trap() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    // 011 can escape to 111, which is not in the initial set.
+    let initial = mk_states(&graph, &[S011, S001, S000]);
+    let config = AttractorConfig::new(graph.clone()).auto_trap(true);
+    let mut generator =
+        XieBeerelAttractors::configure(config.clone(), XieBeerelState::from(&initial));
+    let mut attractors = Vec::new();
+    for result in &mut generator {
+        attractors.push(result?);
+    }
+    verify_attractors(&graph, attractors, &[ATTRACTOR_1]);
+
+    // For a trap set, this is a no-op.
+    let attractors = run_xie_beerel(config, false)?;
+    verify_attractors(&graph, attractors, &[ATTRACTOR_1, ATTRACTOR_2]);
+    Ok(())
+}
+
+#[test]
+fn test_xie_beerel_from_region() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = AttractorConfig::new(graph.clone());
+
+    let run = |region: &[u32]| -> Cancellable<Vec<GraphColoredVertices>> {
+        let state = XieBeerelState::from_region(mk_states(&graph, region));
+        XieBeerelAttractors::configure(config.clone(), state).collect()
+    };
+
+    // 011 can escape to 111, which is not in the region.
+    verify_attractors(&graph, run(&[S011, S001, S000])?, &[ATTRACTOR_1]);
+    // 100 can escape to 000, which is not in the region.
+    verify_attractors(&graph, run(&[S100, S110, S111])?, &[ATTRACTOR_2]);
+    // The region only contains a part of an attractor.
+    verify_attractors(&graph, run(&[S110])?, &[]);
+    Ok(())
+}
+
+#[test]
+fn test_attractors_with_basin_sizes() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let mut result = attractors_with_basin_sizes(&graph)?;
+    result.sort_by_key(|(attractor, _)| attractor.exact_cardinality());
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].0, mk_states(&graph, ATTRACTOR_1));
+    assert_eq!(result[0].1, BigUint::from(CAN_REACH_ATTR1.len()));
+    assert_eq!(result[1].0, mk_states(&graph, ATTRACTOR_2));
+    assert_eq!(result[1].1, BigUint::from(CAN_REACH_ATTR2.len()));
+    // The weak basins overlap (e.g., 011 can reach both attractors).
+    assert!(result[0].1.clone() + result[1].1.clone() > BigUint::from(8u32));
+    Ok(())
+}
+
+#[test]
+fn test_attractor_signatures() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let signatures = attractor_signatures(&graph)?;
+    let expected = AttractorSignature {
+        sizes: vec![BigUint::from(1u32), BigUint::from(2u32)],
+    };
+    assert_eq!(signatures.len(), 1);
+    assert_eq!(signatures[&expected], graph.mk_unit_colors());
+
+    let graph = create_parameterized_test_network();
+    let signatures = attractor_signatures(&graph)?;
+    assert_eq!(signatures.len(), 2);
+    // p=false: two oscillations {00, 10} and {01, 11}.
+    let p_false = &signatures[&AttractorSignature {
+        sizes: vec![BigUint::from(2u32), BigUint::from(2u32)],
+    }];
+    // p=true: fixed point 00 and the cycle {01, 10, 11}.
+    let p_true = &signatures[&AttractorSignature {
+        sizes: vec![BigUint::from(1u32), BigUint::from(3u32)],
+    }];
+    assert_eq!(p_false.exact_cardinality(), BigUint::from(1u32));
+    assert_eq!(p_true.exact_cardinality(), BigUint::from(1u32));
+    assert_eq!(p_false.union(p_true), graph.mk_unit_colors());
+    Ok(())
+}
+
+#[test]
+fn test_max_transient_length() -> Cancellable<()> {
+    init_logger();
+    // Every transient state has a direct successor in an attractor (e.g., 011 -> 111).
+    let graph = create_test_network();
+    assert_eq!(max_transient_length(&graph)?, 1);
+
+    // The longest transient is 00 -> 01 -> 11.
+    let bn = from_transitions(2, &[(0b00, 0b01), (0b01, 0b11)]).unwrap();
+    let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+    assert_eq!(max_transient_length(&graph)?, 2);
+
+    // A single cycle covering the whole state space has no transient states.
+    let bn = from_transitions(1, &[(0, 1), (1, 0)]).unwrap();
+    let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+    assert_eq!(max_transient_length(&graph)?, 0);
+    Ok(())
+}
+
+#[test]
+fn test_attractor_report() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let mut report = attractor_report(&graph)?;
+    report
+        .attractors
+        .sort_by_key(|it| it.kind == AttractorKind::Cyclic);
+    assert_eq!(report.attractors.len(), 2);
+
+    let fixed_point = &report.attractors[0];
+    assert_eq!(fixed_point.kind, AttractorKind::FixedPoint);
+    assert_eq!(fixed_point.states, "1");
+    assert_eq!(fixed_point.colors, "1");
+    let expected = BTreeMap::from([
+        ("x0".to_string(), false),
+        ("x1".to_string(), false),
+        ("x2".to_string(), false),
+    ]);
+    assert_eq!(fixed_point.representative, expected);
+
+    let cyclic = &report.attractors[1];
+    assert_eq!(cyclic.kind, AttractorKind::Cyclic);
+    assert_eq!(cyclic.states, "2");
+    assert_eq!(cyclic.colors, "1");
+    let expected = BTreeMap::from([
+        ("x0".to_string(), true),
+        ("x1".to_string(), true),
+        ("x2".to_string(), false),
+    ]);
+    assert_eq!(cyclic.representative, expected);
+    Ok(())
+}
+
+#[test]
+fn test_xie_beerel_sink_candidates_first() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = AttractorConfig::new(graph.clone()).sink_candidates_first(true);
+    let attractors = run_xie_beerel(config, false)?;
+    assert_eq!(attractors.len(), 2);
+    // The fixed point is always reported first.
+    assert_eq!(attractors[0], mk_states(&graph, ATTRACTOR_1));
+    assert_eq!(attractors[1], mk_states(&graph, ATTRACTOR_2));
+
+    let config = AttractorConfig::new(graph.clone()).sink_candidates_first(true);
+    let with_itgr = run_xie_beerel(config, true)?;
+    assert_eq!(with_itgr, attractors);
+    Ok(())
+}
+
+#[test]
+fn test_xie_beerel_degenerate_universe() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = AttractorConfig::new(graph.clone());
+
+    // An empty universe is exhausted by the very first step.
+    let mut state = XieBeerelState::from(graph.mk_empty_colored_vertices());
+    let result =
+        <XieBeerelStep as GeneratorStep<_, _, GraphColoredVertices>>::step(&config, &mut state);
+    assert!(matches!(result, Ok(None)));
+
+    // A single state is only reported if it is a fixed point.
+    let fixed_point = mk_states(&graph, &[S000]);
+    let attractors = XieBeerelAttractors::configure(config.clone(), &fixed_point)
+        .collect::<Cancellable<Vec<_>>>()?;
+    assert_eq!(attractors, vec![fixed_point]);
+
+    let transient = mk_states(&graph, &[S011]);
+    let attractors =
+        XieBeerelAttractors::configure(config, &transient).collect::<Cancellable<Vec<_>>>()?;
+    assert!(attractors.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_reachable_attractors() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let attractors = vec![
+        mk_states(&graph, ATTRACTOR_1),
+        mk_states(&graph, ATTRACTOR_2),
+    ];
+    // 011 and 100 can reach both attractors, 001 only the fixed point, 101 only the cycle.
+    let expected: [(u32, &[usize]); 5] = [
+        (S011, &[0, 1]),
+        (S100, &[0, 1]),
+        (S001, &[0]),
+        (S101, &[1]),
+        (S110, &[1]),
+    ];
+    for (state, indices) in expected {
+        let from = mk_states(&graph, &[state]);
+        assert_eq!(reachable_attractors(&graph, &attractors, &from)?, indices);
+    }
+    let empty = graph.mk_empty_colored_vertices();
+    assert!(reachable_attractors(&graph, &attractors, &empty)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_pin_color_matches_concrete_network() -> Cancellable<()> {
+    init_logger();
+    let graph = create_parameterized_test_network();
+
+    let attractors_of = |config: AttractorConfig| -> Cancellable<Vec<Vec<u32>>> {
+        let graph = config.graph.clone();
+        let mut attractors = XieBeerelAttractors::configure(config, XieBeerelState::from(&graph))
+            .map(|it| it.map(|set| collect_state_numbers(&graph, &set, 2)))
+            .collect::<Cancellable<Vec<_>>>()?;
+        attractors.sort();
+        Ok(attractors)
+    };
+
+    let mut pinned = Vec::new();
+    let mut remaining = graph.mk_unit_colors();
+    while !remaining.is_empty() {
+        let color = remaining.pick_singleton();
+        remaining = remaining.minus(&color);
+        pinned.push(attractors_of(
+            AttractorConfig::new(graph.clone()).pin_color(&color),
+        )?);
+    }
+    pinned.sort();
+
+    let mut concrete = Vec::new();
+    // The concrete networks for p=false and p=true.
+    let regulations = "A -?? A\nB -?? A\nA -?? B\nB -?? B\n";
+    for functions in ["$A: !A\n$B: B", "$A: A ^ B\n$B: A ^ B"] {
+        let bn = BooleanNetwork::try_from(format!("{regulations}{functions}").as_str()).unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        concrete.push(attractors_of(AttractorConfig::new(graph))?);
+    }
+    concrete.sort();
+
+    // p=false: {00, 10} and {01, 11}; p=true: {00} and {01, 10, 11}.
+    assert_eq!(
+        concrete,
+        vec![vec![vec![0], vec![1, 2, 3]], vec![vec![0, 2], vec![1, 3]]]
+    );
+    assert_eq!(pinned, concrete);
+    Ok(())
+}
+
+#[test]
+fn test_itgr_with_variable_subset() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let unit = graph.mk_unit_colored_vertices();
+    let attractors = mk_states(&graph, ATTRACTOR_1).union(&mk_states(&graph, ATTRACTOR_2));
+    let variables = graph.variables().collect::<Vec<_>>();
+
+    let full = InterleavedTransitionGuidedReduction::configure(
+        AttractorConfig::new(graph.clone()),
+        ItgrState::new(&graph, &unit),
+    )
+    .compute()?;
+
+    for candidates in [&variables[..0], &variables[..1], &variables[..]] {
+        let reduced = InterleavedTransitionGuidedReduction::configure(
+            AttractorConfig::new(graph.clone()),
+            ItgrState::new_with_variables(&graph, &unit, candidates),
+        )
+        .compute()?;
+        assert!(attractors.is_subset(&reduced));
+        assert!(full.is_subset(&reduced));
+        if candidates.is_empty() {
+            // Without candidates, nothing can be reduced.
+            assert_eq!(reduced, unit);
+        }
+        if candidates.len() == variables.len() {
+            assert_eq!(reduced, full);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_xie_beerel_from_itgr() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let unit = graph.mk_unit_colored_vertices();
+    let mut config = AttractorConfig::new(graph.clone());
+
+    let mut itgr = InterleavedTransitionGuidedReduction::configure(
+        config.clone(),
+        ItgrState::new(&graph, &unit),
+    );
+    let reduced = itgr.compute()?;
+    config.active_variables = itgr.state().active_variables().collect();
+
+    let initial_state = XieBeerelState::from_itgr(itgr.state());
+    let mut attractors = Vec::new();
+    for result in XieBeerelAttractors::configure(config, initial_state) {
+        attractors.push(result?);
+    }
+    assert_eq!(attractors, run_xie_beerel(&graph, true)?);
+    assert!(attractors.iter().all(|it| it.is_subset(&reduced)));
+    verify_attractors(&graph, attractors, &[ATTRACTOR_1, ATTRACTOR_2]);
+    Ok(())
+}
+
+#[test]
+fn test_oscillating_variables() {
+    init_logger();
+    let graph = create_test_network();
+    let vars = graph.variables().collect::<Vec<_>>();
+
+    let fixed_point = mk_states(&graph, ATTRACTOR_1);
+    assert!(oscillating_variables(&graph, &fixed_point).is_empty());
+    // 110 <-> 111: only the last variable changes.
+    let cycle = mk_states(&graph, ATTRACTOR_2);
+    assert_eq!(
+        oscillating_variables(&graph, &cycle),
+        HashSet::from([vars[2]])
+    );
+
+    // With two colors, a variable only oscillates if both values occur in the same color.
+    let graph = create_parameterized_test_network();
+    let vars = graph.variables().collect::<Vec<_>>();
+    let color_1 = graph.mk_unit_colors().pick_singleton();
+    let color_2 = graph.mk_unit_colors().minus(&color_1);
+
+    let set = mk_states(&graph, &[0b00, 0b10]);
+    assert_eq!(
+        oscillating_variables(&graph, &set),
+        HashSet::from([vars[0]])
+    );
+    let set = mk_states(&graph, &[0b00])
+        .intersect_colors(&color_1)
+        .union(&mk_states(&graph, &[0b10]).intersect_colors(&color_2));
+    assert!(oscillating_variables(&graph, &set).is_empty());
+    let set = set.union(&mk_states(&graph, &[0b11]).intersect_colors(&color_1));
+    assert_eq!(
+        oscillating_variables(&graph, &set),
+        HashSet::from_iter(vars)
+    );
+}
@@ -1,20 +1,118 @@
-use crate::attractor::AttractorConfig;
-use crate::log_set;
+use crate::attractor::{AttractorConfig, ItgrState};
 use crate::reachability::{
     BackwardReachability, ReachabilityConfig, ReachabilityStep, SaturationSuccessors,
 };
+use crate::trapping::restrict_to_forward_trap;
+use crate::trimming::{TrimComputation, TrimSetting};
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
 use computation_process::Incomplete::Suspended;
-use computation_process::{Completable, Computable, GeneratorStep, Stateful};
+use computation_process::{Algorithm, Completable, Computable, GeneratorStep, Stateful};
 use log::{debug, info};
 
 /// Internal state of the Xie-Beerel attractor algorithm.
+///
+/// The state is typically created from a [`SymbolicAsyncGraph`] (the search covers the whole
+/// state space), or from a [`GraphColoredVertices`] universe. Note that the algorithm is only
+/// correct if the universe is a forward trap (see [`AttractorConfig::auto_trap`]). To search
+/// an arbitrary region, use [`XieBeerelState::from_region`].
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct XieBeerelState {
     computing: Step,
+    /// The explored universe (after the optional forward trap reduction).
+    universe: GraphColoredVertices,
+    /// The states that are already accounted for (basins and trimmed states).
+    covered: GraphColoredVertices,
+    /// The states that were removed from the remaining set, but do not belong to the basin of
+    /// any reported attractor yet (trimmed states and basins of pivots that did not lead to
+    /// an attractor in some colors). Each such state can reach an attractor that is found later.
+    unassigned: GraphColoredVertices,
     remaining: GraphColoredVertices,
     pivot_hint: Option<GraphColoredVertices>,
+    stats: XieBeerelStats,
+    trapped: bool,
+    reduce_to_trap: bool,
+    /// Fixed points of the universe (only computed if
+    /// [`AttractorConfig::sink_candidates_first`] is set).
+    sinks: Option<GraphColoredVertices>,
+}
+
+/// Statistics about the search performed by the Xie-Beerel attractor algorithm.
+///
+/// The statistics are accumulated by [`XieBeerelState`] and can be read at any time using
+/// [`XieBeerelState::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct XieBeerelStats {
+    /// The number of selected pivots (i.e., the number of started basin computations).
+    pub pivots: usize,
+    /// The total number of steps performed by the backward reachability procedure.
+    pub backward_steps: usize,
+    /// The largest symbolic size (BDD nodes) of the basin, attractor candidate,
+    /// or remaining set observed so far.
+    pub peak_symbolic_size: usize,
+    /// The number of attractors emitted so far.
+    pub attractors: usize,
+}
+
+impl XieBeerelStats {
+    fn observe(&mut self, set: &GraphColoredVertices) {
+        self.peak_symbolic_size = self.peak_symbolic_size.max(set.symbolic_size());
+    }
+}
+
+impl XieBeerelState {
+    /// Create a state that searches for attractors within an arbitrary `region`.
+    ///
+    /// Before the search starts, the region is reduced to its greatest forward trap
+    /// (regardless of [`AttractorConfig::auto_trap`]). As such, the emitted attractors are
+    /// exactly the bottom SCCs of the graph that are fully contained in the `region`.
+    pub fn from_region(region: GraphColoredVertices) -> XieBeerelState {
+        XieBeerelState {
+            reduce_to_trap: true,
+            ..XieBeerelState::from(region)
+        }
+    }
+
+    /// Create a state that continues the search in the universe reduced by a (finished)
+    /// [`ItgrState`].
+    ///
+    /// ITGR only removes regions that contain no attractors, and it discards each basin as soon
+    /// as the corresponding region is removed. Hence, the removed regions are exactly the states
+    /// that are no longer in the remaining set, and this is equivalent to creating the state
+    /// from the result of [`InterleavedTransitionGuidedReduction`]. The reported attractors are
+    /// the same as with the full universe (assuming ITGR started with a forward trap).
+    /// To also reuse the eliminated variables, set [`AttractorConfig::active_variables`] using
+    /// [`ItgrState::active_variables`].
+    ///
+    /// [`InterleavedTransitionGuidedReduction`]: crate::attractor::InterleavedTransitionGuidedReduction
+    pub fn from_itgr(itgr: &ItgrState) -> XieBeerelState {
+        XieBeerelState::from(itgr.remaining_set())
+    }
+
+    /// Statistics about the search performed so far.
+    pub fn stats(&self) -> &XieBeerelStats {
+        &self.stats
+    }
+
+    /// Check that the explored universe is covered by the basins of the reported attractors
+    /// (together with the trimmed states, which contain no attractors), returning the
+    /// uncovered states (if any).
+    ///
+    /// The coverage is tracked independently of the remaining set, so once the search is
+    /// finished, a non-empty result indicates a region that was skipped. However, if the
+    /// search was stopped early (e.g., due to [`AttractorConfig::max_attractors`] or
+    /// [`AttractorConfig::required_intersection`]), the unexplored states are reported as well.
+    /// For parametrized networks, the basins are collected per color.
+    pub fn check_coverage(&self) -> Result<(), GraphColoredVertices> {
+        let uncovered = self.universe.minus(&self.covered);
+        if uncovered.is_empty() {
+            Ok(())
+        } else {
+            Err(uncovered)
+        }
+    }
 }
 
 /// Step implementation for the Xie-Beerel attractor algorithm.
@@ -28,6 +126,7 @@ enum Step {
     Idle,
     Basin(StepBasin),
     Attractor(StepAttractor),
+    Trimming(TrimComputation),
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -52,8 +151,63 @@ impl GeneratorStep<AttractorConfig, XieBeerelState, GraphColoredVertices> for Xi
         context: &AttractorConfig,
         state: &mut XieBeerelState,
     ) -> Completable<Option<GraphColoredVertices>> {
+        let result = XieBeerelStep::advance(context, state, false)?;
+        Ok(result.map(|(attractor, _basin)| attractor))
+    }
+}
+
+impl GeneratorStep<AttractorConfig, XieBeerelState, (GraphColoredVertices, GraphColoredVertices)>
+    for XieBeerelStep
+{
+    fn step(
+        context: &AttractorConfig,
+        state: &mut XieBeerelState,
+    ) -> Completable<Option<(GraphColoredVertices, GraphColoredVertices)>> {
+        XieBeerelStep::advance(context, state, true)
+    }
+}
+
+impl XieBeerelStep {
+    /// Advance the algorithm, returning the next attractor together with its basin once found.
+    ///
+    /// The basin consists of the remaining states that can reach the attractor. If `with_basins`
+    /// is set, it also includes the unassigned states (see [`XieBeerelState::unassigned`]) that
+    /// can reach it. Otherwise, the basin is not needed and this extra step is skipped.
+    fn advance(
+        context: &AttractorConfig,
+        state: &mut XieBeerelState,
+        with_basins: bool,
+    ) -> Completable<Option<(GraphColoredVertices, GraphColoredVertices)>> {
+        if state.stats.attractors >= context.max_attractors {
+            // The requested number of attractors has been reported. The rest of the
+            // universe remains unexplored in the state.
+            info!(
+                "{}Reached the limit of {} attractors.",
+                LogLabel(&context.label),
+                context.max_attractors
+            );
+            return Ok(None);
+        }
+
         match &mut state.computing {
             Step::Idle => {
+                if (context.auto_trap || state.reduce_to_trap) && !state.trapped {
+                    state.remaining =
+                        restrict_to_forward_trap(&context.graph, &state.remaining, &context.label)?;
+                    state.universe = state.remaining.clone();
+                    state.trapped = true;
+                }
+
+                if context.sink_candidates_first && state.sinks.is_none() {
+                    let sinks = fixed_points(context, &state.remaining);
+                    info!(
+                        "{}Computed fixed points ({}).",
+                        LogLabel(&context.label),
+                        log_set(&sinks)
+                    );
+                    state.sinks = Some(sinks);
+                }
+
                 // Find a new pivot and start basin computation:
 
                 if state.remaining.is_empty() {
@@ -61,13 +215,33 @@ impl GeneratorStep<AttractorConfig, XieBeerelState, GraphColoredVertices> for Xi
                     return Ok(None);
                 }
 
+                if let Some(candidates) = &context.required_intersection
+                    && state.remaining.intersect(candidates).is_empty()
+                {
+                    // No remaining attractor can intersect the candidate set.
+                    info!(
+                        "{}Remaining states do not intersect the candidate set.",
+                        LogLabel(&context.label)
+                    );
+                    return Ok(None);
+                }
+
                 info!(
-                    "Start next iteration. Remaining ({}).",
+                    "{}Start next iteration. Remaining ({}).",
+                    LogLabel(&context.label),
                     log_set(&state.remaining),
                 );
+                state.stats.pivots += 1;
+                state.stats.observe(&state.remaining);
 
-                // Try to use a pivot hint (if any) to select the next pivot:
-                let pivot_hint = if let Some(hint) = state.pivot_hint.take() {
+                // Prefer fixed points (if enabled), then the pivot hint (if any):
+                let sinks = match &state.sinks {
+                    Some(sinks) => sinks.intersect(&state.remaining),
+                    None => context.graph.mk_empty_colored_vertices(),
+                };
+                let pivot_hint = if !sinks.is_empty() {
+                    sinks
+                } else if let Some(hint) = state.pivot_hint.take() {
                     hint.intersect(&state.remaining)
                 } else {
                     context.graph.mk_empty_colored_vertices()
@@ -89,7 +263,9 @@ impl GeneratorStep<AttractorConfig, XieBeerelState, GraphColoredVertices> for Xi
             }
             Step::Basin(step) => {
                 // Basin is just computed fully without any special treatment:
+                state.stats.backward_steps += 1;
                 let basin = step.basin.try_compute()?;
+                state.stats.observe(&basin);
                 state.computing = Step::Attractor(StepAttractor {
                     basin,
                     attractor: step.pivot.clone(),
@@ -103,25 +279,52 @@ impl GeneratorStep<AttractorConfig, XieBeerelState, GraphColoredVertices> for Xi
                     SaturationSuccessors::step(&step.attractor_config, &step.attractor)?;
                 if successors.is_subset(&step.attractor) {
                     info!(
-                        "Attractor ({}) and basin ({}) iteration done.",
+                        "{}Attractor ({}) and basin ({}) iteration done.",
+                        LogLabel(&context.label),
                         log_set(&step.attractor),
                         log_set(&step.basin),
                     );
 
                     // Attractor computation is done! Remove the basin and report the attractor.
-                    let attractor = step.attractor.clone();
+                    let mut attractor = step.attractor.clone();
+                    if let Some(candidates) = &context.required_intersection {
+                        let valid_colors = attractor.intersect(candidates).colors();
+                        attractor = attractor.intersect_colors(&valid_colors);
+                    }
+                    let mut basin = step.basin.intersect_colors(&attractor.colors());
+                    if with_basins && !state.unassigned.is_empty() {
+                        let mut config = ReachabilityConfig::from(context);
+                        config.graph = config.graph.restrict(&state.unassigned.union(&basin));
+                        basin = BackwardReachability::run(config, basin)?;
+                    }
+                    // In colors without an attractor, the basin of the pivot can reach
+                    // some attractor that is found later.
+                    state.unassigned = state.unassigned.union(&step.basin).minus(&basin);
                     state.remaining = state.remaining.minus(&step.basin);
+                    state.covered = state.covered.union(&step.basin);
                     state.pivot_hint = Some(step.future_pivots.clone());
-                    state.computing = Step::Idle;
+                    state.computing = if context.trim_between_pivots == TrimSetting::None {
+                        Step::Idle
+                    } else {
+                        // Use the same variables and limits as the other steps, such that
+                        // trimming agrees with `fixed_points`.
+                        Step::Trimming(context.trim_between_pivots.build_computation(
+                            ReachabilityConfig::from(context),
+                            state.remaining.clone(),
+                        ))
+                    };
                     if attractor.is_empty() {
                         Err(Suspended)
                     } else {
-                        Ok(Some(attractor))
+                        state.stats.attractors += 1;
+                        Ok(Some((attractor, basin)))
                     }
                 } else {
                     step.attractor = step.attractor.union(&successors);
+                    state.stats.observe(&step.attractor);
                     debug!(
-                        "Attractor candidates increased ({}).",
+                        "{}Attractor candidates increased ({}).",
+                        LogLabel(&context.label),
                         log_set(&step.attractor)
                     );
 
@@ -131,7 +334,8 @@ impl GeneratorStep<AttractorConfig, XieBeerelState, GraphColoredVertices> for Xi
                     let escaped = successors.minus(&step.basin);
                     if !escaped.is_empty() {
                         debug!(
-                            "Removing {} colors that escape attractor basin.",
+                            "{}Removing {} colors that escape attractor basin.",
+                            LogLabel(&context.label),
                             escaped.exact_cardinality()
                         );
                         step.attractor = step.attractor.minus_colors(&escaped.colors());
@@ -141,10 +345,40 @@ impl GeneratorStep<AttractorConfig, XieBeerelState, GraphColoredVertices> for Xi
                     Err(Suspended)
                 }
             }
+            Step::Trimming(trimming) => {
+                let trimmed = trimming.try_compute()?;
+
+                // Fixed points are trivial sinks (and possibly sources), meaning trimming can
+                // remove them. However, they are also attractors, so we have to put them back.
+                let removed = state.remaining.minus(&trimmed);
+                let fixed_points = fixed_points(context, &removed);
+
+                state.remaining = trimmed.union(&fixed_points);
+                state.covered = state.covered.union(&removed.minus(&fixed_points));
+                state.unassigned = state.unassigned.union(&removed.minus(&fixed_points));
+                debug!(
+                    "{}Remaining set trimmed ({}); retained fixed points ({}).",
+                    LogLabel(&context.label),
+                    log_set(&state.remaining),
+                    log_set(&fixed_points),
+                );
+
+                state.computing = Step::Idle;
+                Err(Suspended)
+            }
         }
     }
 }
 
+/// The states of `set` that have no successors (using the active variables of the `context`).
+fn fixed_points(context: &AttractorConfig, set: &GraphColoredVertices) -> GraphColoredVertices {
+    let mut has_successor = context.graph.mk_empty_colored_vertices();
+    for var in &context.active_variables {
+        has_successor = has_successor.union(&context.graph.var_can_post(*var, set));
+    }
+    set.minus(&has_successor)
+}
+
 impl From<&SymbolicAsyncGraph> for XieBeerelState {
     fn from(value: &SymbolicAsyncGraph) -> Self {
         XieBeerelState::from(value.mk_unit_colored_vertices())
@@ -161,8 +395,15 @@ impl From<GraphColoredVertices> for XieBeerelState {
     fn from(value: GraphColoredVertices) -> Self {
         XieBeerelState {
             computing: Step::Idle,
+            covered: value.minus(&value),
+            unassigned: value.minus(&value),
+            universe: value.clone(),
             remaining: value,
             pivot_hint: None,
+            stats: XieBeerelStats::default(),
+            trapped: false,
+            reduce_to_trap: false,
+            sinks: None,
         }
     }
 }
@@ -1,7 +1,9 @@
 //! Generate Boolean Networks from lists of state transitions.
 //!
 //! This module provides utilities to construct Boolean Networks by specifying
-//! the asynchronous state transition graph directly as a list of edges.
+//! the asynchronous state transition graph directly as a list of edges. Symbolic graphs
+//! of existing models can be loaded from `.aeon`, `.bnet`, or `.sbml` files using
+//! [`load_graph`].
 //!
 //! # Overview
 //!
@@ -15,9 +17,8 @@
 //!
 //! # Example
 //!
-//! ```ignore
-//! // This module is only available during testing.
-//! use crate::test_utils::llm_transition_builder::from_transitions;
+//! ```
+//! use biodivine_algo_bdd_scc::build::from_transitions;
 //!
 //! // Define transitions for a 2-variable network:
 //! // 00 → 10 (variable 0 flips to 1)
@@ -34,8 +35,10 @@
 //! let bn = from_transitions(2, &transitions).expect("Failed to create network");
 //! ```
 
-use biodivine_lib_param_bn::BooleanNetwork;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use biodivine_lib_param_bn::{BooleanNetwork, VariableId};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
 /// Represents a transition from one state to another.
 /// States are represented as integers where the binary encoding corresponds
@@ -248,9 +251,8 @@ fn find_variables_in_dnf(function_true_states: &HashSet<u32>, num_vars: usize) -
 ///
 /// # Example
 ///
-/// ```ignore
-/// // This module is only available during testing.
-/// use crate::test_utils::llm_transition_builder::from_transitions;
+/// ```
+/// use biodivine_algo_bdd_scc::build::from_transitions;
 ///
 /// // Create a simple 2-variable cycle: 00 → 10 → 11 → 01 → 00
 /// let transitions = vec![
@@ -304,7 +306,7 @@ pub fn from_transitions(
         // Record that this variable can update from this state
         variables_that_update
             .entry(from)
-            .or_insert_with(HashSet::new)
+            .or_default()
             .insert(var_idx);
 
         // Determine the new value of the changed variable
@@ -321,15 +323,15 @@ pub fn from_transitions(
 
     // For states with transitions, set functions for variables that DON'T update
     // to their current values (so they don't change)
-    for (&state, &ref updating_vars) in &variables_that_update {
-        for j in 0..num_vars {
+    for (&state, updating_vars) in &variables_that_update {
+        for (j, true_states) in function_true_states.iter_mut().enumerate() {
             if !updating_vars.contains(&j) {
                 // This variable doesn't update from this state, so f_j(state) = state[j]
                 let current_value = get_variable_value(state, j, num_vars);
                 if current_value {
-                    function_true_states[j].insert(state);
+                    true_states.insert(state);
                 } else {
-                    function_true_states[j].remove(&state);
+                    true_states.remove(&state);
                 }
             }
         }
@@ -339,9 +341,9 @@ pub fn from_transitions(
     // set f_i(s) = s[i]
     for state in 0..=max_state {
         if !states_with_transitions.contains(&state) {
-            for i in 0..num_vars {
+            for (i, true_states) in function_true_states.iter_mut().enumerate() {
                 if get_variable_value(state, i, num_vars) {
-                    function_true_states[i].insert(state);
+                    true_states.insert(state);
                 }
             }
         }
@@ -355,8 +357,8 @@ pub fn from_transitions(
 
     // Add update functions in DNF first (we need them to determine dependencies)
     let mut dnf_functions = Vec::new();
-    for i in 0..num_vars {
-        let dnf = states_to_dnf(&function_true_states[i], num_vars, &var_names);
+    for true_states in &function_true_states {
+        let dnf = states_to_dnf(true_states, num_vars, &var_names);
         dnf_functions.push(dnf.clone());
     }
 
@@ -392,12 +394,208 @@ pub fn from_transitions(
         .map_err(|e| TransitionError::ParseError(format!("{:?}", e)))
 }
 
+/// Create a Boolean Network from a map that assigns each state its list of successors.
+///
+/// This is equivalent to [`from_transitions`] with the map flattened into a list of
+/// transitions: each transition is validated using the same rules (and reported using the same
+/// [`TransitionError`]), and states that are absent from the map (or have no successors)
+/// become fixed points.
+///
+/// # Example
+///
+/// ```
+/// use biodivine_algo_bdd_scc::build::from_successor_map;
+/// use std::collections::HashMap;
+///
+/// // 00 → 10, 00 → 01; 10 and 01 lead back to 00; 11 is a fixed point.
+/// let successors = HashMap::from([
+///     (0b00, vec![0b10, 0b01]),
+///     (0b10, vec![0b00]),
+///     (0b01, vec![0b00]),
+/// ]);
+///
+/// let bn = from_successor_map(2, &successors).expect("Failed to create network");
+/// assert_eq!(bn.num_vars(), 2);
+/// ```
+pub fn from_successor_map(
+    num_vars: usize,
+    successors: &HashMap<u32, Vec<u32>>,
+) -> Result<BooleanNetwork, TransitionError> {
+    // Sort the states to make error reporting deterministic.
+    let mut states = successors.keys().copied().collect::<Vec<_>>();
+    states.sort();
+
+    let transitions = states
+        .into_iter()
+        .flat_map(|from| successors[&from].iter().map(move |to| (from, *to)))
+        .collect::<Vec<Transition>>();
+
+    from_transitions(num_vars, &transitions)
+}
+
+/// Error type for name-based state construction (see [`StateBuilder`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StateError {
+    /// The network has no variable with the given name.
+    UnknownVariable { name: String },
+    /// The variable has no assigned value.
+    MissingVariable { name: String },
+    /// The variable was assigned both `true` and `false`.
+    ConflictingValue { name: String },
+}
+
+impl std::fmt::Display for StateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::UnknownVariable { name } => {
+                write!(f, "Unknown variable `{}`", name)
+            }
+            StateError::MissingVariable { name } => {
+                write!(f, "Variable `{}` has no assigned value", name)
+            }
+            StateError::ConflictingValue { name } => {
+                write!(f, "Variable `{}` is assigned conflicting values", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StateError {}
+
+/// Construct a single state of a [`SymbolicAsyncGraph`] from variable names.
+///
+/// Unlike the bit-order state numbers used by [`from_transitions`], the values are given
+/// using variable names, which are resolved against the network once the state is built.
+/// Every variable must be assigned exactly one value.
+///
+/// # Example
+///
+/// ```
+/// use biodivine_algo_bdd_scc::build::{StateBuilder, from_transitions};
+/// use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+///
+/// let bn = from_transitions(2, &[(0b00, 0b10)]).unwrap();
+/// let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+///
+/// let state = StateBuilder::new()
+///     .value("x0", true)
+///     .value("x1", false)
+///     .build(&graph)
+///     .unwrap();
+/// assert_eq!(state.exact_cardinality(), 1u32.into());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StateBuilder {
+    values: Vec<(String, bool)>,
+}
+
+impl StateBuilder {
+    /// Create a new builder with no assigned variables.
+    pub fn new() -> StateBuilder {
+        StateBuilder::default()
+    }
+
+    /// Assign `value` to the variable called `name`.
+    pub fn value(mut self, name: impl Into<String>, value: bool) -> StateBuilder {
+        self.values.push((name.into(), value));
+        self
+    }
+
+    /// Resolve the variable names against the `graph` and build the state as a singleton
+    /// set (with all colors of the `graph`).
+    pub fn build(&self, graph: &SymbolicAsyncGraph) -> Result<GraphColoredVertices, StateError> {
+        let context = graph.symbolic_context();
+        let mut valuation: HashMap<VariableId, bool> = HashMap::new();
+        for (name, value) in &self.values {
+            let Some(var) = context.find_network_variable(name) else {
+                return Err(StateError::UnknownVariable { name: name.clone() });
+            };
+            if *valuation.entry(var).or_insert(*value) != *value {
+                return Err(StateError::ConflictingValue { name: name.clone() });
+            }
+        }
+
+        let mut subspace = Vec::new();
+        for var in graph.variables() {
+            let Some(value) = valuation.get(&var) else {
+                let name = context.get_network_variable_name(var);
+                return Err(StateError::MissingVariable { name });
+            };
+            subspace.push((var, *value));
+        }
+        Ok(graph.mk_subspace(&subspace))
+    }
+}
+
+/// Error type for loading a graph from a model file (see [`load_graph`]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphLoadError {
+    /// The file could not be read.
+    Io { path: String, message: String },
+    /// The file extension is not one of `.aeon`, `.bnet`, or `.sbml`.
+    UnsupportedFormat { path: String },
+    /// The file contents are not a valid model of the detected format.
+    ParseError(String),
+    /// The network could not be converted to a [`SymbolicAsyncGraph`].
+    GraphError(String),
+}
+
+impl std::fmt::Display for GraphLoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphLoadError::Io { path, message } => {
+                write!(f, "Cannot read `{}`: {}", path, message)
+            }
+            GraphLoadError::UnsupportedFormat { path } => {
+                write!(
+                    f,
+                    "Unsupported model format of `{}` (expected .aeon, .bnet, or .sbml)",
+                    path
+                )
+            }
+            GraphLoadError::ParseError(msg) => {
+                write!(f, "Failed to parse model: {}", msg)
+            }
+            GraphLoadError::GraphError(msg) => {
+                write!(f, "Failed to create symbolic graph: {}", msg)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphLoadError {}
+
+/// Load a [`SymbolicAsyncGraph`] from a model file.
+///
+/// The format is determined by the file extension (case-insensitive): `.aeon`, `.bnet`,
+/// or `.sbml` (SBML-qual). The model is parsed using the respective parser of
+/// `biodivine-lib-param-bn`, and any layout information in SBML files is ignored.
+pub fn load_graph(path: impl AsRef<Path>) -> Result<SymbolicAsyncGraph, GraphLoadError> {
+    let path = path.as_ref();
+    let display = path.display().to_string();
+    let extension = path
+        .extension()
+        .and_then(|it| it.to_str())
+        .map(|it| it.to_ascii_lowercase());
+    let contents = std::fs::read_to_string(path).map_err(|e| GraphLoadError::Io {
+        path: display.clone(),
+        message: e.to_string(),
+    });
+    let bn = match extension.as_deref() {
+        Some("aeon") => BooleanNetwork::try_from(contents?.as_str()),
+        Some("bnet") => BooleanNetwork::try_from_bnet(contents?.as_str()),
+        Some("sbml") => BooleanNetwork::try_from_sbml(contents?.as_str()).map(|(bn, _)| bn),
+        _ => return Err(GraphLoadError::UnsupportedFormat { path: display }),
+    }
+    .map_err(GraphLoadError::ParseError)?;
+    SymbolicAsyncGraph::new(&bn).map_err(GraphLoadError::GraphError)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::test_utils::{collect_state_numbers, mk_state};
     use biodivine_lib_param_bn::biodivine_std::traits::Set;
-    use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
 
     /// Verify that all declared transitions exist in the graph.
     fn verify_transitions(graph: &SymbolicAsyncGraph, transitions: &[Transition], num_vars: usize) {
@@ -430,10 +628,7 @@ mod tests {
         // Build a map of declared transitions: from_state -> set of to_states
         let mut declared_transitions: HashMap<u32, HashSet<u32>> = HashMap::new();
         for &(from, to) in transitions {
-            declared_transitions
-                .entry(from)
-                .or_insert_with(HashSet::new)
-                .insert(to);
+            declared_transitions.entry(from).or_default().insert(to);
         }
 
         // Check each state that should have transitions
@@ -651,4 +846,121 @@ mod tests {
         // Verify exact transition structure
         verify_exact_transitions(&graph, &transitions, 3);
     }
+
+    #[test]
+    fn test_successor_map() {
+        // The same network as `test_3var_example`, plus an extra branch 100 → 101.
+        let successors = HashMap::from([
+            (0b000, vec![0b100]),
+            (0b100, vec![0b110, 0b101]),
+            (0b110, vec![0b111]),
+            (0b001, vec![]),
+        ]);
+        let transitions = vec![
+            (0b000, 0b100),
+            (0b100, 0b110),
+            (0b100, 0b101),
+            (0b110, 0b111),
+        ];
+
+        let bn = from_successor_map(3, &successors).expect("Failed to create network");
+        let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+
+        // States with an empty successor list or absent from the map are fixed points.
+        verify_exact_transitions(&graph, &transitions, 3);
+    }
+
+    #[test]
+    fn test_successor_map_invalid_transition() {
+        let successors = HashMap::from([(0b00, vec![0b10]), (0b01, vec![0b10])]);
+        let result = from_successor_map(2, &successors);
+        assert_eq!(
+            result.unwrap_err(),
+            TransitionError::MultipleVariablesChanged {
+                from: 0b01,
+                to: 0b10
+            }
+        );
+    }
+
+    #[test]
+    fn test_state_builder() {
+        let bn = from_transitions(2, &[(0b00, 0b10)]).expect("Failed to create network");
+        let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+
+        let state = StateBuilder::new()
+            .value("x1", true)
+            .value("x0", false)
+            .build(&graph)
+            .unwrap();
+        assert_eq!(state, mk_state(&graph, 0b01));
+
+        let error = StateBuilder::new()
+            .value("x0", true)
+            .value("y", false)
+            .build(&graph);
+        assert_eq!(
+            error,
+            Err(StateError::UnknownVariable {
+                name: "y".to_string()
+            })
+        );
+
+        let error = StateBuilder::new().value("x0", true).build(&graph);
+        assert_eq!(
+            error,
+            Err(StateError::MissingVariable {
+                name: "x1".to_string()
+            })
+        );
+
+        let error = StateBuilder::new()
+            .value("x0", true)
+            .value("x1", true)
+            .value("x0", false)
+            .build(&graph);
+        assert_eq!(
+            error,
+            Err(StateError::ConflictingValue {
+                name: "x0".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_load_graph() {
+        let dir = std::env::temp_dir().join(format!("load-graph-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let aeon = dir.join("model.aeon");
+        std::fs::write(&aeon, "A -> B\nB -| A\n$A: !B\n$B: A\n").unwrap();
+        let graph = load_graph(&aeon).unwrap();
+        assert_eq!(graph.variables().count(), 2);
+
+        let bnet = dir.join("model.BNET");
+        std::fs::write(&bnet, "targets, factors\nA, !B\nB, A\n").unwrap();
+        let graph = load_graph(&bnet).unwrap();
+        assert_eq!(graph.variables().count(), 2);
+
+        let unknown = dir.join("model.txt");
+        std::fs::write(&unknown, "A -> B\n").unwrap();
+        assert!(matches!(
+            load_graph(&unknown),
+            Err(GraphLoadError::UnsupportedFormat { .. })
+        ));
+
+        let invalid = dir.join("invalid.aeon");
+        std::fs::write(&invalid, "A -> \n").unwrap();
+        assert!(matches!(
+            load_graph(&invalid),
+            Err(GraphLoadError::ParseError(_))
+        ));
+
+        assert!(matches!(
+            load_graph(dir.join("missing.aeon")),
+            Err(GraphLoadError::Io { .. })
+        ));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,165 @@
+//! Cheap comparisons of symbolic set cardinalities against small thresholds.
+//!
+//! The exact cardinality of a symbolic set is a [`BigUint`](num_bigint::BigUint), which is
+//! unnecessarily expensive when we only need to check the size against a small threshold
+//! (e.g., to guard explicit enumeration). The [`CardinalityExt`] trait first uses the
+//! approximate (floating point) cardinality and only computes the exact value when the
+//! approximation is not conclusive.
+//!
+//! Additionally, [`transition_count`] and [`transition_count_per_color`] count the
+//! transitions of a graph within a set (e.g., for density metrics).
+
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
+use num_bigint::BigUint;
+
+/// Below this value, the approximate cardinality is exact (all integers up to `2^53` are
+/// representable as `f64`, and the BDD model counting only adds and doubles such numbers).
+const EXACT_APPROX_LIMIT: f64 = 9_007_199_254_740_992.0; // 2^53
+
+/// Above this value, the cardinality certainly does not fit into `u64`, even with
+/// the rounding errors of the approximation.
+const OVERFLOW_APPROX_LIMIT: f64 = 36_893_488_147_419_103_232.0; // 2^65
+
+/// Extension methods for comparing the cardinality of symbolic sets with `u64` values.
+pub trait CardinalityExt {
+    /// The exact cardinality of the set, or `None` if it does not fit into `u64`.
+    fn fits_u64(&self) -> Option<u64>;
+
+    /// True if the cardinality of the set is strictly larger than `threshold`.
+    fn is_larger_than(&self, threshold: u64) -> bool {
+        self.fits_u64().is_none_or(|count| count > threshold)
+    }
+}
+
+impl CardinalityExt for GraphColoredVertices {
+    fn fits_u64(&self) -> Option<u64> {
+        let approx = self.approx_cardinality();
+        if approx < EXACT_APPROX_LIMIT {
+            Some(approx as u64)
+        } else if approx > OVERFLOW_APPROX_LIMIT {
+            None
+        } else {
+            u64::try_from(&self.exact_cardinality()).ok()
+        }
+    }
+}
+
+/// Count the transitions of the `graph` between the states of `set`.
+///
+/// Every asynchronous transition updates exactly one variable, so the count is the sum of
+/// `|var_can_post_within(var, set)|` over all variables (no transition is counted twice).
+/// For parametrized networks, the result is the number of transition-color pairs, i.e.,
+/// a transition that exists in `k` colors is counted `k` times (see
+/// [`transition_count_per_color`]).
+pub fn transition_count(graph: &SymbolicAsyncGraph, set: &GraphColoredVertices) -> BigUint {
+    graph
+        .variables()
+        .map(|var| graph.var_can_post_within(var, set).exact_cardinality())
+        .sum()
+}
+
+/// Count the transitions of the `graph` between the states of `set` for each color of `set`
+/// separately (see [`transition_count`]).
+///
+/// The result contains one (singleton) color and its count for each color of `set`, so this
+/// is only practical for networks with a small number of colors.
+pub fn transition_count_per_color(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Vec<(GraphColors, BigUint)> {
+    let mut result = Vec::new();
+    let mut remaining = set.colors();
+    while !remaining.is_empty() {
+        let color = remaining.pick_singleton();
+        remaining = remaining.minus(&color);
+        let count = transition_count(graph, &set.intersect_colors(&color));
+        result.push((color, count));
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CardinalityExt, transition_count, transition_count_per_color};
+    use crate::test_utils::llm_example_network::sets::ATTRACTOR_2;
+    use crate::test_utils::llm_example_network::{
+        create_parameterized_test_network, create_test_network,
+    };
+    use crate::test_utils::mk_states;
+    use biodivine_lib_param_bn::BooleanNetwork;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
+    use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn small_sets_fit_u64() {
+        let graph = create_test_network();
+        assert_eq!(graph.mk_empty_colored_vertices().fits_u64(), Some(0));
+        assert_eq!(graph.mk_unit_colored_vertices().fits_u64(), Some(8));
+
+        let attractor = mk_states(&graph, ATTRACTOR_2);
+        assert_eq!(attractor.fits_u64(), Some(2));
+        assert!(attractor.is_larger_than(1));
+        assert!(!attractor.is_larger_than(2));
+        assert!(!graph.mk_empty_colored_vertices().is_larger_than(0));
+    }
+
+    #[test]
+    fn large_sets_do_not_fit_u64() {
+        // 70 independent variables with identity update functions.
+        let mut aeon = String::new();
+        for i in 0..70 {
+            aeon.push_str(&format!("v{i} -> v{i}\n$v{i}: v{i}\n"));
+        }
+        let bn = BooleanNetwork::try_from(aeon.as_str()).unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let unit = graph.mk_unit_colored_vertices();
+        assert_eq!(unit.fits_u64(), None);
+        assert!(unit.is_larger_than(u64::MAX));
+
+        // Exactly 2^63 states: the approximation is not conclusive, the exact value is used.
+        let mut set = unit.clone();
+        for var in graph.variables().take(7) {
+            set = set.intersect(&graph.fix_network_variable(var, true));
+        }
+        assert_eq!(set.fits_u64(), Some(1 << 63));
+        assert!(!set.is_larger_than(1 << 63));
+    }
+
+    #[test]
+    fn count_transitions() {
+        let graph = create_test_network();
+        let unit = graph.mk_unit_colored_vertices();
+        // See the example network: 001->000, 010->000, 011->{001,010,111},
+        // 100->{000,110}, 101->111, 110<->111.
+        assert_eq!(transition_count(&graph, &unit), BigUint::from(10u32));
+        assert_eq!(
+            transition_count(&graph, &mk_states(&graph, ATTRACTOR_2)),
+            BigUint::from(2u32)
+        );
+        assert_eq!(
+            transition_count(&graph, &graph.mk_empty_colored_vertices()),
+            BigUint::from(0u32)
+        );
+
+        let graph = create_parameterized_test_network();
+        let unit = graph.mk_unit_colored_vertices();
+        let per_color = transition_count_per_color(&graph, &unit);
+        assert_eq!(per_color.len(), 2);
+        let mut counts = per_color
+            .iter()
+            .map(|(_, count)| count.clone())
+            .collect::<Vec<_>>();
+        counts.sort();
+        // p=false: A flips in every state; p=true: 01 -> 11, 10 -> 11, 11 -> {01, 10}.
+        assert_eq!(counts, vec![BigUint::from(4u32), BigUint::from(4u32)]);
+        let total = per_color
+            .into_iter()
+            .map(|(_, count)| count)
+            .sum::<BigUint>();
+        assert_eq!(transition_count(&graph, &unit), total);
+    }
+}
@@ -0,0 +1,411 @@
+//! Generic combinators for [`Computable`] objects.
+//!
+//! Since all computations in this crate are performed in small cancellable steps, they can be
+//! freely interleaved within a single thread. The combinators in this module use this property
+//! to compose several computations into one. Additionally, the module contains adapters
+//! for generators (iterators) of symbolic sets, like [`disjointify`] or [`collect_capped`], and
+//! lazy item transformations that keep errors intact ([`map_gen`] and [`filter_gen`]).
+//! For profiling, [`timed`] measures the time spent in any computation or generator.
+
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use cancel_this::Cancellable;
+use computation_process::Incomplete::Suspended;
+use computation_process::{Completable, Computable};
+use std::time::{Duration, Instant};
+
+/// A computation that alternately advances two computations producing the same result type
+/// and returns the result of the one that finishes first.
+///
+/// The two computations are advanced in a round-robin fashion, switching whenever the active
+/// computation suspends. Cancellation of either computation cancels the whole race. Create
+/// instances using [`race`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Race<A, B> {
+    first: A,
+    second: B,
+    first_active: bool,
+}
+
+/// Race computations `a` and `b` (see [`Race`]).
+///
+/// The result is equal to the result of the computation that finishes first. Hence, it only
+/// makes sense to race computations that are expected to produce the same output
+/// (e.g., two different algorithms for the same problem).
+pub fn race<T, A: Computable<T>, B: Computable<T>>(a: A, b: B) -> Race<A, B> {
+    Race {
+        first: a,
+        second: b,
+        first_active: true,
+    }
+}
+
+impl<T, A: Computable<T>, B: Computable<T>> Computable<T> for Race<A, B> {
+    fn try_compute(&mut self) -> Completable<T> {
+        let result = if self.first_active {
+            self.first.try_compute()
+        } else {
+            self.second.try_compute()
+        };
+
+        if let Err(Suspended) = result {
+            self.first_active = !self.first_active;
+        }
+
+        result
+    }
+}
+
+/// A wrapper that measures the time spent in each step of the `inner` computation.
+///
+/// For a [`Computable`], each call to [`Computable::try_compute`] is one step. For a generator
+/// (or any other iterator), each call to [`Iterator::next`] is one step (i.e., a generator step
+/// covers all the work needed to produce the next item). The results of the inner computation
+/// are passed through unchanged. Create instances using [`timed`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timed<C> {
+    inner: C,
+    step_durations: Vec<Duration>,
+}
+
+/// Measure the time spent in the steps of `inner` (see [`Timed`]).
+pub fn timed<C>(inner: C) -> Timed<C> {
+    Timed {
+        inner,
+        step_durations: Vec::new(),
+    }
+}
+
+impl<C> Timed<C> {
+    /// The total time spent in all steps so far.
+    pub fn total(&self) -> Duration {
+        self.step_durations.iter().sum()
+    }
+
+    /// The durations of the individual steps performed so far.
+    pub fn step_durations(&self) -> &[Duration] {
+        &self.step_durations
+    }
+
+    /// The wrapped computation.
+    pub fn inner(&self) -> &C {
+        &self.inner
+    }
+
+    /// Unwrap the measured computation.
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<T, C: Computable<T>> Computable<T> for Timed<C> {
+    fn try_compute(&mut self) -> Completable<T> {
+        let start = Instant::now();
+        let result = self.inner.try_compute();
+        self.step_durations.push(start.elapsed());
+        result
+    }
+}
+
+impl<I: Iterator> Iterator for Timed<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = Instant::now();
+        let result = self.inner.next();
+        self.step_durations.push(start.elapsed());
+        result
+    }
+}
+
+/// A generator adapter that makes the emitted sets pairwise disjoint.
+///
+/// The adapter tracks the union of all previously emitted sets and subtracts it from each
+/// new set, skipping sets that become empty. For algorithms that already produce disjoint
+/// sets (e.g., any [`SccAlgorithm`](crate::scc::SccAlgorithm)), this has no effect.
+/// Create instances using [`disjointify`].
+pub struct Disjointify<G> {
+    inner: G,
+    emitted: Option<GraphColoredVertices>,
+}
+
+/// Make the sets emitted by `inner` pairwise disjoint (see [`Disjointify`]).
+pub fn disjointify<G>(inner: G) -> Disjointify<G>
+where
+    G: Iterator<Item = Cancellable<GraphColoredVertices>>,
+{
+    Disjointify {
+        inner,
+        emitted: None,
+    }
+}
+
+impl<G> Iterator for Disjointify<G>
+where
+    G: Iterator<Item = Cancellable<GraphColoredVertices>>,
+{
+    type Item = Cancellable<GraphColoredVertices>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let set = match self.inner.next()? {
+                Ok(set) => set,
+                Err(e) => return Some(Err(e)),
+            };
+            let fresh = match &self.emitted {
+                Some(emitted) => set.minus(emitted),
+                None => set,
+            };
+            if fresh.is_empty() {
+                continue;
+            }
+            self.emitted = Some(match &self.emitted {
+                Some(emitted) => emitted.union(&fresh),
+                None => fresh.clone(),
+            });
+            return Some(Ok(fresh));
+        }
+    }
+}
+
+/// A generator adapter that lazily applies a function to every emitted item.
+///
+/// Errors (e.g., cancellation) are passed through unchanged and the function is not
+/// applied to them. Create instances using [`map_gen`].
+pub struct MapGen<G, F> {
+    inner: G,
+    function: F,
+}
+
+/// Apply `function` to every item emitted by `inner` (see [`MapGen`]).
+pub fn map_gen<T, U, G, F>(inner: G, function: F) -> MapGen<G, F>
+where
+    G: Iterator<Item = Cancellable<T>>,
+    F: FnMut(T) -> U,
+{
+    MapGen { inner, function }
+}
+
+impl<T, U, G, F> Iterator for MapGen<G, F>
+where
+    G: Iterator<Item = Cancellable<T>>,
+    F: FnMut(T) -> U,
+{
+    type Item = Cancellable<U>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.inner.next()?.map(&mut self.function))
+    }
+}
+
+/// A generator adapter that lazily skips the emitted items which do not satisfy a predicate.
+///
+/// Errors (e.g., cancellation) are always passed through, so a cancelled generator is never
+/// mistaken for an exhausted one. Create instances using [`filter_gen`].
+pub struct FilterGen<G, P> {
+    inner: G,
+    predicate: P,
+}
+
+/// Only keep the items emitted by `inner` which satisfy the `predicate` (see [`FilterGen`]).
+pub fn filter_gen<T, G, P>(inner: G, predicate: P) -> FilterGen<G, P>
+where
+    G: Iterator<Item = Cancellable<T>>,
+    P: FnMut(&T) -> bool,
+{
+    FilterGen { inner, predicate }
+}
+
+impl<T, G, P> Iterator for FilterGen<G, P>
+where
+    G: Iterator<Item = Cancellable<T>>,
+    P: FnMut(&T) -> bool,
+{
+    type Item = Cancellable<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.inner.next()? {
+                Ok(item) if !(self.predicate)(&item) => continue,
+                result => return Some(result),
+            }
+        }
+    }
+}
+
+/// The result of [`collect_capped`]: the collected `items` and an indication whether
+/// the generator had more items than the limit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Capped<T> {
+    /// The first (at most `limit`) items emitted by the generator.
+    pub items: Vec<T>,
+    /// True if the generator emitted more than `limit` items, i.e., `items` is incomplete.
+    pub truncated: bool,
+}
+
+/// Collect at most `limit` items of the `inner` generator (e.g., the first 100 SCCs).
+///
+/// To reliably detect truncation, the generator is advanced once more after `limit` items are
+/// collected (the extra item is discarded). Hence, collection of a truncated result costs one
+/// additional item, but the rest of the generator is never explored. The first error stops
+/// the collection and is returned.
+pub fn collect_capped<T, G>(inner: G, limit: usize) -> Cancellable<Capped<T>>
+where
+    G: IntoIterator<Item = Cancellable<T>>,
+{
+    let mut inner = inner.into_iter();
+    let mut items = Vec::new();
+    while items.len() < limit {
+        match inner.next() {
+            Some(item) => items.push(item?),
+            None => {
+                return Ok(Capped {
+                    items,
+                    truncated: false,
+                });
+            }
+        }
+    }
+    let truncated = inner.next().transpose()?.is_some();
+    Ok(Capped { items, truncated })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::combinators::{
+        Capped, collect_capped, disjointify, filter_gen, map_gen, race, timed,
+    };
+    use crate::reachability::{ForwardReachability, ForwardReachabilityBfs};
+    use crate::scc::FwdBwdScc;
+    use crate::test_utils::llm_example_network::create_test_network;
+    use crate::test_utils::llm_example_network::states::{S000, S001, S011, S110, S111};
+    use crate::test_utils::{mk_state, mk_states};
+    use cancel_this::{Cancellable, Cancelled};
+    use computation_process::{Algorithm, Computable, Stateful};
+    use num_bigint::BigUint;
+
+    #[test]
+    fn race_reachability() -> Cancellable<()> {
+        let graph = create_test_network();
+        let initial = mk_state(&graph, S011);
+        let expected = ForwardReachability::run(&graph, initial.clone())?;
+
+        let mut computation = race(
+            ForwardReachability::configure(&graph, initial.clone()),
+            ForwardReachabilityBfs::configure(&graph, initial.clone()),
+        );
+        assert_eq!(computation.compute()?, expected);
+
+        let mut computation = race(
+            ForwardReachabilityBfs::configure(&graph, initial.clone()),
+            ForwardReachability::configure(&graph, initial),
+        );
+        assert_eq!(computation.compute()?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn disjointify_overlapping_sets() -> Cancellable<()> {
+        let graph = create_test_network();
+        let sets = vec![
+            Ok(mk_states(&graph, &[S000, S001])),
+            Ok(mk_states(&graph, &[S001])),
+            Ok(mk_states(&graph, &[S001, S011])),
+        ];
+        let result = disjointify(sets.into_iter()).collect::<Cancellable<Vec<_>>>()?;
+        assert_eq!(
+            result,
+            vec![mk_states(&graph, &[S000, S001]), mk_state(&graph, S011)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn disjointify_scc_is_identity() -> Cancellable<()> {
+        let graph = create_test_network();
+        let sccs =
+            disjointify(FwdBwdScc::configure(&graph, &graph)).collect::<Cancellable<Vec<_>>>()?;
+        assert_eq!(sccs, vec![mk_states(&graph, &[S110, S111])]);
+        Ok(())
+    }
+
+    #[test]
+    fn collect_capped_reports_truncation() -> Cancellable<()> {
+        let items = || vec![Ok(1), Ok(2), Ok(3)];
+        assert_eq!(
+            collect_capped(items(), 2)?,
+            Capped {
+                items: vec![1, 2],
+                truncated: true
+            }
+        );
+        assert_eq!(
+            collect_capped(items(), 3)?,
+            Capped {
+                items: vec![1, 2, 3],
+                truncated: false
+            }
+        );
+        assert!(collect_capped(items(), 0)?.truncated);
+        assert!(!collect_capped(items(), 10)?.truncated);
+
+        let graph = create_test_network();
+        let sccs = collect_capped(FwdBwdScc::configure(&graph, &graph), 1)?;
+        assert_eq!(sccs.items, vec![mk_states(&graph, &[S110, S111])]);
+        assert!(!sccs.truncated);
+        Ok(())
+    }
+
+    #[test]
+    fn timed_computation_and_generator() -> Cancellable<()> {
+        let graph = create_test_network();
+        let initial = mk_state(&graph, S011);
+        let expected = ForwardReachability::run(&graph, initial.clone())?;
+
+        let mut computation = timed(ForwardReachabilityBfs::configure(&graph, initial));
+        assert_eq!(computation.compute()?, expected);
+        // BFS needs several suspended steps before the result is available.
+        assert!(computation.step_durations().len() > 1);
+        assert_eq!(
+            computation.total(),
+            computation.step_durations().iter().sum()
+        );
+
+        let mut sccs = timed(FwdBwdScc::configure(&graph, &graph));
+        assert_eq!(
+            sccs.by_ref().collect::<Cancellable<Vec<_>>>()?,
+            vec![mk_states(&graph, &[S110, S111])]
+        );
+        // One step for the SCC and one for the end of the iteration.
+        assert_eq!(sccs.step_durations().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn map_and_filter_generators() -> Cancellable<()> {
+        let graph = create_test_network();
+        let sizes = map_gen(FwdBwdScc::configure(&graph, &graph), |it| {
+            it.exact_cardinality()
+        })
+        .collect::<Cancellable<Vec<_>>>()?;
+        assert_eq!(sizes, vec![BigUint::from(2u32)]);
+
+        let sccs = filter_gen(FwdBwdScc::configure(&graph, &graph), |it| {
+            it.exact_cardinality() > BigUint::from(2u32)
+        })
+        .collect::<Cancellable<Vec<_>>>()?;
+        assert!(sccs.is_empty());
+
+        // Errors are never filtered out or transformed.
+        let items = || vec![Ok(1), Err(Cancelled::new("test")), Ok(3)];
+        let mut filtered = filter_gen(items().into_iter(), |it| *it > 1);
+        assert!(filtered.next().unwrap().is_err());
+        assert_eq!(filtered.next().unwrap()?, 3);
+        assert!(filtered.next().is_none());
+        let mapped = map_gen(items().into_iter(), |it| it * 2).collect::<Vec<_>>();
+        assert_eq!(mapped.len(), 3);
+        assert!(mapped[1].is_err());
+        assert!(matches!(mapped[2], Ok(6)));
+        Ok(())
+    }
+}
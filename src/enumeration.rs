@@ -0,0 +1,471 @@
+//! Explicit enumeration of small symbolic sets.
+//!
+//! The algorithms in this crate work with symbolic sets that can be arbitrarily large. However,
+//! for small sets, it is often useful to convert the result into an explicit representation
+//! that can be consumed by other tools (e.g., `networkx`).
+//!
+//! All methods in this module enumerate the sets explicitly, hence they should only be used
+//! for sets with a small number of vertices. To prevent accidental misuse, each method
+//! checks that the enumerated set has at most [`MAX_ENUMERATION_SIZE`] vertices (or a limit
+//! given by the caller, see [`enumerate_states`]) and reports an [`EnumerationError`] otherwise.
+//!
+//! For parametrized networks, colors are ignored: a vertex (or a transition) is enumerated
+//! if it exists for at least one color of the set.
+
+use biodivine_lib_param_bn::VariableId;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use num_bigint::BigUint;
+use std::collections::BTreeMap;
+
+/// The maximal number of vertices that can be explicitly enumerated by the methods
+/// in this module.
+pub const MAX_ENUMERATION_SIZE: u64 = 1 << 16;
+
+/// The maximal number of network variables for which vertices can be represented as
+/// state numbers (see [`export_states`]).
+pub const MAX_STATE_NUMBER_VARIABLES: usize = 32;
+
+/// Error type for explicit enumeration of symbolic sets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EnumerationError {
+    /// The network has too many variables to represent its vertices as state numbers
+    /// (see [`MAX_STATE_NUMBER_VARIABLES`]).
+    TooManyVariables { num_vars: usize },
+    /// The enumerated set has more vertices than the `limit` (typically
+    /// [`MAX_ENUMERATION_SIZE`]).
+    TooManyVertices { vertices: BigUint, limit: u64 },
+}
+
+impl std::fmt::Display for EnumerationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnumerationError::TooManyVariables { num_vars } => {
+                write!(
+                    f,
+                    "Network with {} variables is too large to enumerate (max: {})",
+                    num_vars, MAX_STATE_NUMBER_VARIABLES
+                )
+            }
+            EnumerationError::TooManyVertices { vertices, limit } => {
+                write!(
+                    f,
+                    "Set with {} vertices is too large to enumerate (max: {})",
+                    vertices, limit
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for EnumerationError {}
+
+/// Check that `set` has at most `limit` vertices.
+fn check_enumeration_size(set: &GraphColoredVertices, limit: u64) -> Result<(), EnumerationError> {
+    let vertices = set.vertices().exact_cardinality();
+    if vertices > limit.into() {
+        return Err(EnumerationError::TooManyVertices { vertices, limit });
+    }
+    Ok(())
+}
+
+/// Enumerate all vertices of `set` as state numbers, where the binary encoding of a number
+/// corresponds to the vertex valuation (the most significant bit = variable 0, same as in
+/// [`build`](crate::build)). The numbers are sorted in increasing order.
+///
+/// Returns [`EnumerationError`] if the network has more than [`MAX_STATE_NUMBER_VARIABLES`]
+/// variables or if `set` contains more than [`MAX_ENUMERATION_SIZE`] vertices.
+pub fn export_states(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Result<Vec<u32>, EnumerationError> {
+    let num_vars = graph.variables().len();
+    if num_vars > MAX_STATE_NUMBER_VARIABLES {
+        return Err(EnumerationError::TooManyVariables { num_vars });
+    }
+    check_enumeration_size(set, MAX_ENUMERATION_SIZE)?;
+
+    let mut states = Vec::new();
+    let mut remaining = set.clone();
+    while !remaining.is_empty() {
+        let valuation = pick_smallest_valuation(graph, &remaining);
+        remaining = remaining.minus(&mk_valuation(graph, &valuation));
+        let state = state_number(&valuation);
+        states.push(state);
+    }
+
+    Ok(states)
+}
+
+/// Describe the difference between two sets as state numbers (see [`export_states`]), which
+/// is useful for debugging step operators, e.g., `added=[3, 7]; removed=[1]`.
+///
+/// At most `limit` states are listed for each direction; the remaining states are only
+/// counted (e.g., `added=[0, 1, ... (+10 more)]`). This makes the method safe to use even for
+/// large differences. If the network has more than [`MAX_STATE_NUMBER_VARIABLES`] variables,
+/// only the number of added and removed vertices is reported.
+pub fn log_diff(
+    graph: &SymbolicAsyncGraph,
+    before: &GraphColoredVertices,
+    after: &GraphColoredVertices,
+    limit: usize,
+) -> String {
+    let added = after.minus(before);
+    let removed = before.minus(after);
+    if graph.variables().len() > MAX_STATE_NUMBER_VARIABLES {
+        return format!(
+            "added={} vertices; removed={} vertices",
+            added.vertices().exact_cardinality(),
+            removed.vertices().exact_cardinality()
+        );
+    }
+    format!(
+        "added=[{}]; removed=[{}]",
+        format_states(graph, &added, limit),
+        format_states(graph, &removed, limit)
+    )
+}
+
+/// Format at most `limit` (smallest) vertices of `set` as a comma-separated list of state
+/// numbers, followed by the number of omitted vertices (if any).
+fn format_states(graph: &SymbolicAsyncGraph, set: &GraphColoredVertices, limit: usize) -> String {
+    let mut states = Vec::new();
+    let mut remaining = set.clone();
+    while !remaining.is_empty() && states.len() < limit {
+        let valuation = pick_smallest_valuation(graph, &remaining);
+        remaining = remaining.minus(&mk_valuation(graph, &valuation));
+        let state = state_number(&valuation);
+        states.push(state.to_string());
+    }
+    if !remaining.is_empty() {
+        states.push(format!(
+            "... (+{} more)",
+            remaining.vertices().exact_cardinality()
+        ));
+    }
+    states.join(", ")
+}
+
+/// Enumerate all vertices of `set` as explicit variable valuations. The valuations are sorted
+/// lexicographically (using the order of [`SymbolicAsyncGraph::variables`]).
+///
+/// Unlike [`export_states`], this does not depend on a particular bit order and works for
+/// networks with any number of variables, as long as `set` is small: the caller specifies
+/// the maximal number of vertices `max_vertices`, and [`EnumerationError::TooManyVertices`]
+/// is returned if `set` is larger.
+pub fn enumerate_states(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    max_vertices: u64,
+) -> Result<Vec<BTreeMap<VariableId, bool>>, EnumerationError> {
+    check_enumeration_size(set, max_vertices)?;
+
+    let mut states = Vec::new();
+    let mut remaining = set.clone();
+    while !remaining.is_empty() {
+        let valuation = pick_smallest_valuation(graph, &remaining);
+        remaining = remaining.minus(&mk_valuation(graph, &valuation));
+        states.push(graph.variables().zip(valuation).collect());
+    }
+
+    Ok(states)
+}
+
+/// A transition given as a pair of `(source, target)` variable valuations (see
+/// [`export_edges`]).
+pub type ValuationEdge = (Vec<bool>, Vec<bool>);
+
+/// Enumerate all transitions of `graph` that start and end in `set`.
+///
+/// Each transition is represented as a pair of variable valuations, where the valuations
+/// follow the order of [`SymbolicAsyncGraph::variables`]. The transitions are sorted
+/// lexicographically by their source vertex (and then by the updated variable).
+///
+/// Returns [`EnumerationError`] if `set` contains more than [`MAX_ENUMERATION_SIZE`] vertices.
+pub fn export_edges(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Result<Vec<ValuationEdge>, EnumerationError> {
+    check_enumeration_size(set, MAX_ENUMERATION_SIZE)?;
+
+    let mut edges = Vec::new();
+    let mut remaining = set.clone();
+    while !remaining.is_empty() {
+        let source = pick_smallest_valuation(graph, &remaining);
+        let source_set = mk_valuation(graph, &source);
+        remaining = remaining.minus(&source_set);
+
+        let source_set = source_set.intersect(set);
+        for (index, var) in graph.variables().enumerate() {
+            let successor = graph.var_post(var, &source_set).intersect(set);
+            if !successor.is_empty() {
+                let mut target = source.clone();
+                target[index] = !target[index];
+                edges.push((source.clone(), target));
+            }
+        }
+    }
+
+    Ok(edges)
+}
+
+/// Pick the lexicographically smallest vertex (using the order of
+/// [`SymbolicAsyncGraph::variables`]) of `set` for each color of `set`.
+///
+/// This is a deterministic alternative to [`GraphColoredVertices::pick_vertex`], which
+/// depends on the internal structure of the BDD. Unlike the other methods in this module,
+/// this does not enumerate the set explicitly, but it performs one symbolic operation
+/// per variable, making it slightly slower than `pick_vertex`.
+pub fn pick_smallest_vertex(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> GraphColoredVertices {
+    let mut candidates = set.clone();
+    for var in graph.variables() {
+        let with_false = candidates.intersect(&graph.mk_subspace(&[(var, false)]));
+        let with_true = candidates
+            .intersect(&graph.mk_subspace(&[(var, true)]))
+            .minus_colors(&with_false.colors());
+        candidates = with_false.union(&with_true);
+    }
+    candidates
+}
+
+/// Pick up to `n` disjoint vertex samples from `set` by repeatedly applying
+/// [`GraphColoredVertices::pick_vertex`] and removing the picked vertices from the set.
+///
+/// The result has fewer than `n` items only if the set is exhausted. For networks without
+/// parameters, each item is a single vertex. For parametrized networks, `pick_vertex` selects
+/// one vertex for each color, i.e., each item contains exactly one vertex per color (possibly
+/// different vertices for different colors), and an item only covers the colors that still
+/// have some unpicked vertex. Like the other methods in this module, this does not depend on
+/// randomness, but the exact picked vertices depend on the structure of the BDD.
+pub fn pick_vertices(set: &GraphColoredVertices, n: usize) -> Vec<GraphColoredVertices> {
+    let mut remaining = set.clone();
+    let mut result = Vec::new();
+    while result.len() < n && !remaining.is_empty() {
+        let vertex = remaining.pick_vertex();
+        remaining = remaining.minus(&vertex);
+        result.push(vertex);
+    }
+    result
+}
+
+/// Find the lexicographically smallest vertex valuation in a non-empty `set` (using the
+/// order of [`SymbolicAsyncGraph::variables`]).
+pub(crate) fn pick_smallest_valuation(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Vec<bool> {
+    let mut candidates = set.clone();
+    let mut valuation = Vec::new();
+    for var in graph.variables() {
+        let with_false = candidates.intersect(&graph.mk_subspace(&[(var, false)]));
+        if with_false.is_empty() {
+            candidates = candidates.intersect(&graph.mk_subspace(&[(var, true)]));
+            valuation.push(true);
+        } else {
+            candidates = with_false;
+            valuation.push(false);
+        }
+    }
+    valuation
+}
+
+/// Convert a vertex valuation into a state number (the most significant bit = variable 0).
+fn state_number(valuation: &[bool]) -> u32 {
+    valuation
+        .iter()
+        .fold(0u32, |acc, bit| (acc << 1) | u32::from(*bit))
+}
+
+/// Create a set containing the given vertex valuation (with all colors).
+fn mk_valuation(graph: &SymbolicAsyncGraph, valuation: &[bool]) -> GraphColoredVertices {
+    let assignment = graph
+        .variables()
+        .zip(valuation.iter().copied())
+        .collect::<Vec<_>>();
+    graph.mk_subspace(&assignment)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::enumeration::{
+        EnumerationError, MAX_ENUMERATION_SIZE, enumerate_states, export_edges, export_states,
+        log_diff, pick_smallest_vertex, pick_vertices,
+    };
+    use crate::test_utils::llm_example_network::create_test_network;
+    use crate::test_utils::llm_example_network::sets::{
+        ATTRACTOR_2, CAN_REACH_ATTR1, STRONG_BASIN_ATTR1,
+    };
+    use crate::test_utils::llm_example_network::states::{S000, S001, S110};
+    use crate::test_utils::mk_states;
+    use biodivine_lib_param_bn::BooleanNetwork;
+    use biodivine_lib_param_bn::biodivine_std::traits::Set;
+    use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+    use num_bigint::BigUint;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn export_attractor_edges() {
+        let graph = create_test_network();
+        let attractor = mk_states(&graph, ATTRACTOR_2);
+        let edges = export_edges(&graph, &attractor).unwrap();
+        assert_eq!(
+            edges,
+            vec![
+                (vec![true, true, false], vec![true, true, true]),
+                (vec![true, true, true], vec![true, true, false]),
+            ]
+        );
+    }
+
+    #[test]
+    fn export_edges_ignores_outside_transitions() {
+        let graph = create_test_network();
+        // Both states only lead to `000`, which is not in the set.
+        let basin = mk_states(&graph, STRONG_BASIN_ATTR1);
+        assert!(export_edges(&graph, &basin).unwrap().is_empty());
+
+        let mut with_target = STRONG_BASIN_ATTR1.to_vec();
+        with_target.push(S000);
+        let basin = mk_states(&graph, &with_target);
+        assert_eq!(
+            export_edges(&graph, &basin).unwrap(),
+            vec![
+                (vec![false, false, true], vec![false, false, false]),
+                (vec![false, true, false], vec![false, false, false]),
+            ]
+        );
+    }
+
+    #[test]
+    fn pick_smallest_vertex_in_set() {
+        let graph = create_test_network();
+        let set = mk_states(&graph, &[S110, S001, S000]);
+        assert_eq!(
+            pick_smallest_vertex(&graph, &set),
+            mk_states(&graph, &[S000])
+        );
+        let set = mk_states(&graph, &[S110, S001]);
+        assert_eq!(
+            pick_smallest_vertex(&graph, &set),
+            mk_states(&graph, &[S001])
+        );
+        assert!(pick_smallest_vertex(&graph, &graph.mk_empty_colored_vertices()).is_empty());
+    }
+
+    #[test]
+    fn export_states_in_order() {
+        let graph = create_test_network();
+        let set = mk_states(&graph, &[S110, S001, S000]);
+        assert_eq!(export_states(&graph, &set).unwrap(), vec![S000, S001, S110]);
+        assert!(
+            export_states(&graph, &graph.mk_empty_colored_vertices())
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn enumeration_errors() {
+        // 40 independent variables with identity update functions.
+        let mut aeon = String::new();
+        for i in 0..40 {
+            aeon.push_str(&format!("v{i} -> v{i}\n$v{i}: v{i}\n"));
+        }
+        let bn = BooleanNetwork::try_from(aeon.as_str()).unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let unit = graph.mk_unit_colored_vertices();
+        assert_eq!(
+            export_states(&graph, &unit),
+            Err(EnumerationError::TooManyVariables { num_vars: 40 })
+        );
+        assert_eq!(
+            export_edges(&graph, &unit),
+            Err(EnumerationError::TooManyVertices {
+                vertices: BigUint::from(1u64 << 40),
+                limit: MAX_ENUMERATION_SIZE,
+            })
+        );
+    }
+
+    #[test]
+    fn enumerate_states_as_valuations() {
+        let graph = create_test_network();
+        let vars = graph.variables().collect::<Vec<_>>();
+        let set = mk_states(&graph, &[S110, S001]);
+        let states = enumerate_states(&graph, &set, 2).unwrap();
+        assert_eq!(states.len(), 2);
+        assert_eq!(
+            states[0],
+            BTreeMap::from([(vars[0], false), (vars[1], false), (vars[2], true)])
+        );
+        assert_eq!(
+            states[1],
+            BTreeMap::from([(vars[0], true), (vars[1], true), (vars[2], false)])
+        );
+        assert_eq!(
+            enumerate_states(&graph, &set, 1),
+            Err(EnumerationError::TooManyVertices {
+                vertices: BigUint::from(2u32),
+                limit: 1,
+            })
+        );
+
+        // Small sets can be enumerated even in large networks.
+        let mut aeon = String::new();
+        for i in 0..40 {
+            aeon.push_str(&format!("v{i} -> v{i}\n$v{i}: v{i}\n"));
+        }
+        let bn = BooleanNetwork::try_from(aeon.as_str()).unwrap();
+        let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+        let vertex = graph.mk_unit_colored_vertices().pick_vertex();
+        let states = enumerate_states(&graph, &vertex, 1).unwrap();
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].len(), 40);
+    }
+
+    #[test]
+    fn pick_distinct_vertices() {
+        let graph = create_test_network();
+        let set = mk_states(&graph, CAN_REACH_ATTR1);
+        let picked = pick_vertices(&set, 2);
+        assert_eq!(picked.len(), 2);
+        assert!(picked[0].intersect(&picked[1]).is_empty());
+        for vertex in &picked {
+            assert_eq!(vertex.exact_cardinality(), BigUint::from(1u32));
+            assert!(vertex.is_subset(&set));
+        }
+
+        // If the set is small, all vertices are returned.
+        let picked = pick_vertices(&set, 100);
+        assert_eq!(picked.len(), CAN_REACH_ATTR1.len());
+        let union = picked
+            .iter()
+            .fold(graph.mk_empty_colored_vertices(), |a, b| a.union(b));
+        assert_eq!(union, set);
+        assert!(pick_vertices(&set, 0).is_empty());
+    }
+
+    #[test]
+    fn log_diff_lists_added_and_removed_states() {
+        let graph = create_test_network();
+        let before = mk_states(&graph, &[S000, S001]);
+        let after = mk_states(&graph, &[S001, S110]);
+        assert_eq!(
+            log_diff(&graph, &before, &after, 10),
+            "added=[6]; removed=[0]"
+        );
+        assert_eq!(
+            log_diff(&graph, &before, &before, 10),
+            "added=[]; removed=[]"
+        );
+
+        let unit = graph.mk_unit_colored_vertices();
+        assert_eq!(
+            log_diff(&graph, &before, &unit, 2),
+            "added=[2, 3, ... (+4 more)]; removed=[]"
+        );
+    }
+}
@@ -9,7 +9,20 @@
 //! - [`reachability`]: Forward and backward reachability algorithms (BFS and saturation)
 //! - [`scc`]: SCC detection algorithms (forward-backward and chain-based)
 //! - [`trimming`]: Algorithms for removing trivial sink/source states
+//! - [`trapping`]: Greatest and smallest forward/backward trap sets
 //! - [`attractor`]: Attractor (bottom SCC) enumeration algorithms
+//! - [`build`]: Construction of small Boolean networks from explicit transition lists, and of
+//!   states from variable names (see [`build::StateBuilder`]); loading of model files
+//!   (see [`build::load_graph`])
+//! - [`enumeration`]: Explicit enumeration of small symbolic sets (e.g., edge list export)
+//! - [`combinators`]: Generic combinators of step-based computations (e.g., [`combinators::race`])
+//! - [`cardinality`]: Cheap comparisons of set cardinalities against small thresholds
+//!   and transition counting
+//!
+//! The [`trimming`] and [`trapping`] computations are built on the same step framework as
+//! [`reachability`] (see [`reachability::ReachabilityStep`] and
+//! [`reachability::ReachabilityConfig`]), so all of them share a single implementation
+//! of limits, cancellation, and progress reporting.
 //!
 //! # Quick Start
 //!
@@ -49,6 +62,50 @@
 //! let reachable = ForwardReachability::run(&graph, initial).unwrap();
 //! ```
 //!
+//! # Cancellation
+//!
+//! All algorithms use cooperative cancellation provided by [`cancel_this`]. Cancellation
+//! triggers are not passed to individual computations. Instead, they are registered for
+//! a scope (and thread), and every computation running in that scope checks them. As such,
+//! multiple chained stages can share a single trigger simply by running them in the same scope:
+//!
+//! ```no_run
+//! use biodivine_algo_bdd_scc::attractor::{
+//!     AttractorConfig, InterleavedTransitionGuidedReduction, ItgrState, XieBeerelAttractors,
+//!     XieBeerelState,
+//! };
+//! use biodivine_lib_param_bn::BooleanNetwork;
+//! use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+//! use cancel_this::{CancelAtomic, Cancellable};
+//! use computation_process::{Computable, Stateful};
+//!
+//! let bn = BooleanNetwork::try_from_file("model.aeon").unwrap();
+//! let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+//!
+//! // Calling `stop.cancel()` (e.g., from another thread) aborts whichever stage is active.
+//! let stop = CancelAtomic::new();
+//! let result: Cancellable<Vec<GraphColoredVertices>> = cancel_this::on_atomic(stop.clone(), || {
+//!     let config = AttractorConfig::new(graph.clone());
+//!     let itgr_state = ItgrState::new(&graph, &graph.mk_unit_colored_vertices());
+//!     let reduced = InterleavedTransitionGuidedReduction::configure(config.clone(), itgr_state)
+//!         .compute()?;
+//!     XieBeerelAttractors::configure(config, XieBeerelState::from(&reduced)).collect()
+//! });
+//! ```
+//!
+//! When a trigger is cancelled, [`Computable::try_compute`](computation_process::Computable::try_compute)
+//! (and generator steps) return `Incomplete::Cancelled`, while the methods that run
+//! a computation to completion (e.g., `compute`, `run`, or iterating a generator) return
+//! the [`Cancelled`](cancel_this::Cancelled) error. Note that some algorithms also cancel
+//! themselves when they exceed a configured limit (e.g.,
+//! [`ReachabilityConfig::max_iterations`](reachability::ReachabilityConfig::max_iterations));
+//! such errors can be distinguished using [`Cancelled::cause`](cancel_this::Cancelled::cause).
+//!
+//! Application code therefore never needs to handle `Incomplete::Suspended`: it only signals
+//! that `try_compute` should be called again, which is exactly what `compute` does internally.
+//! The only error of `compute` (and `run`) is [`Cancelled`](cancel_this::Cancelled), which can
+//! be propagated using `?` like any other error.
+//!
 //! # Algorithm Variants
 //!
 //! The crate provides multiple algorithm variants optimized for different scenarios:
@@ -59,10 +116,18 @@
 
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
 
+/// Re-export of the symbolic graph representation used by all algorithms in this crate, such
+/// that it can be used without depending on a matching version of `biodivine-lib-param-bn`.
+pub use biodivine_lib_param_bn::symbolic_async_graph;
+
 #[cfg(test)]
 mod test_utils;
 
 pub mod attractor;
+pub mod build;
+pub mod cardinality;
+pub mod combinators;
+pub mod enumeration;
 pub mod reachability;
 pub mod scc;
 pub mod trapping;
@@ -77,6 +142,18 @@ fn log_set(set: &GraphColoredVertices) -> String {
     )
 }
 
+/// A utility for printing the optional label of an algorithm run as a log message prefix.
+struct LogLabel<'a>(&'a Option<String>);
+
+impl std::fmt::Display for LogLabel<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(label) => write!(f, "[{label}] "),
+            None => Ok(()),
+        }
+    }
+}
+
 /// Extract the "simple name" of a type argument at compile time.
 ///
 /// In the future, this should be a `const fn`, but `type_name` and `unwrap_or` are not
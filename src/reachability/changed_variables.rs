@@ -0,0 +1,30 @@
+use crate::reachability::{ForwardReachability, ReachabilityConfig};
+use biodivine_lib_param_bn::VariableId;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use cancel_this::{Cancellable, is_cancelled};
+use computation_process::Algorithm;
+use std::collections::BTreeSet;
+
+/// Compute the forward reachable set of `initial` (see [`ForwardReachability`]) together with
+/// the variables that change their value at least once within this set.
+///
+/// Since the reachable set is closed under successors, a variable changes at least once on
+/// some path from `initial` if and only if it can be updated in some reachable state. Only the
+/// [`ReachabilityConfig::active_variables`] are considered. For parametrized networks,
+/// a variable is included if it changes for at least one color.
+pub fn forward_with_changed_variables<T: Into<ReachabilityConfig>>(
+    config: T,
+    initial: GraphColoredVertices,
+) -> Cancellable<(GraphColoredVertices, BTreeSet<VariableId>)> {
+    let config = config.into();
+    let reachable = ForwardReachability::run(config.clone(), initial)?;
+    let mut changed = BTreeSet::new();
+    for var in &config.active_variables {
+        is_cancelled!()?;
+        if !config.graph.var_can_post(*var, &reachable).is_empty() {
+            changed.insert(*var);
+        }
+    }
+    Ok((reachable, changed))
+}
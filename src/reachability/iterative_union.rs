@@ -1,6 +1,6 @@
 use crate::reachability::reachability_state::ReachabilityState;
 use crate::reachability::{ReachabilityConfig, ReachabilityStep};
-use crate::{log_set, simple_type_name};
+use crate::{LogLabel, log_set, simple_type_name};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
 use cancel_this::Cancelled;
@@ -23,7 +23,8 @@ impl<S: ReachabilityStep>
     ) -> Completable<GraphColoredVertices> {
         if state.iteration >= context.max_iterations {
             debug!(
-                "[iteration:{}] Union<{}> canceled (exceeded iteration count).",
+                "{}[iteration:{}] Union<{}> canceled (exceeded iteration count).",
+                LogLabel(&context.label),
                 state.iteration,
                 simple_type_name::<S>()
             );
@@ -34,9 +35,11 @@ impl<S: ReachabilityStep>
         }
 
         let to_union = S::step(context, &state.set)?;
+        context.observe_peak(&to_union);
         if to_union.is_empty() {
             debug!(
-                "[iteration:{}] Union<{}> finished with ({}).",
+                "{}[iteration:{}] Union<{}> finished with ({}).",
+                LogLabel(&context.label),
                 state.iteration,
                 simple_type_name::<S>(),
                 log_set(&state.set)
@@ -45,10 +48,12 @@ impl<S: ReachabilityStep>
             Ok(state.set.clone())
         } else {
             state.set = state.set.union(&to_union);
+            context.observe_peak(&state.set);
 
             if state.set.symbolic_size() > context.max_symbolic_size {
                 debug!(
-                    "[iteration:{}] Union<{}> canceled (exceeded symbolic size).",
+                    "{}[iteration:{}] Union<{}> canceled (exceeded symbolic size).",
+                    LogLabel(&context.label),
                     state.iteration,
                     simple_type_name::<S>()
                 );
@@ -57,12 +62,17 @@ impl<S: ReachabilityStep>
             }
 
             debug!(
-                "[iteration:{}] Union<{}> increased to ({}).",
+                "{}[iteration:{}] Union<{}> increased to ({}).",
+                LogLabel(&context.label),
                 state.iteration,
                 simple_type_name::<S>(),
                 log_set(&state.set)
             );
 
+            if let Some(progress) = &context.progress {
+                progress.notify(state.iteration, &state.set);
+            }
+
             Err(Suspended)
         }
     }
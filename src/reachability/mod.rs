@@ -21,6 +21,15 @@
 //! - [`ForwardReachabilityBfs`]: Forward reachability using BFS
 //! - [`BackwardReachabilityBfs`]: Backward reachability using BFS
 //!
+//! For yes/no reachability questions, [`ForwardReachabilityUntil`] stops as soon as
+//! a target set is reached, and [`ForwardReachabilityAvoiding`] only follows paths that
+//! never enter a forbidden set. To also learn which variables change during the exploration,
+//! use [`forward_with_changed_variables`]. To compute the reachable sets of many seeds,
+//! use [`multi_source_forward`], which shares the work between seeds. If a (potentially huge)
+//! reachable set is only needed for membership checks, [`ReachableSet`] provides a narrow
+//! wrapper. For small graphs, [`verify_against_naive`] cross-validates the symbolic result
+//! with an explicit-state search.
+//!
 //! # Example
 //!
 //! ```no_run
@@ -35,24 +44,61 @@
 //! let initial = graph.mk_unit_colored_vertices().pick_vertex();
 //! let reachable = ForwardReachability::run(&graph, initial).unwrap();
 //! ```
+//!
+//! The first argument of `run` (and `configure`) accepts anything that converts into a
+//! [`ReachabilityConfig`], so a prepared configuration can be used in the same way:
+//!
+//! ```no_run
+//! # use biodivine_algo_bdd_scc::reachability::{BackwardReachabilityBfs, ReachabilityConfig};
+//! # use biodivine_lib_param_bn::BooleanNetwork;
+//! # use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+//! # use computation_process::Algorithm;
+//! # let bn = BooleanNetwork::try_from_file("model.aeon").unwrap();
+//! # let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+//! # let initial = graph.mk_unit_colored_vertices().pick_vertex();
+//! let config = ReachabilityConfig::new(graph.clone())
+//!     .with_active_variables(bn.variables().take(2))
+//!     .label("basin");
+//! let basin = BackwardReachabilityBfs::run(config, initial).unwrap();
+//! ```
 
-use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
 use cancel_this::Cancellable;
 use computation_process::{Algorithm, Computation};
 
 #[cfg(test)]
 mod tests;
 
+mod changed_variables;
 mod iterative_union;
+mod multi_source;
+mod naive;
+mod reachability_avoiding;
 mod reachability_config;
 mod reachability_state;
+mod reachability_until;
+mod reachable_set;
 mod step_operators;
 
+pub use changed_variables::forward_with_changed_variables;
 pub use iterative_union::IterativeUnion;
-pub use reachability_config::ReachabilityConfig;
+pub use multi_source::multi_source_forward;
+pub use naive::{reach_forward_naive, verify_against_naive};
+pub use reachability_avoiding::ForwardReachabilityAvoiding;
+pub use reachability_config::{
+    PeakTracker, ProgressCallback, ReachabilityConfig, ReachabilityStrategy, SaturationHint,
+    TransitionGuard,
+};
 pub use reachability_state::ReachabilityState;
+pub use reachability_until::ForwardReachabilityUntil;
+pub use reachable_set::ReachableSet;
 pub use step_operators::{
-    BfsPredecessors, BfsSuccessors, SaturationPredecessors, SaturationSuccessors,
+    AdaptiveSaturationSuccessors, BfsPredecessors, BfsSuccessors, DynPredecessors, DynSuccessors,
+    GuardedSaturationSuccessors, SaturationPredecessors, SaturationSuccessors,
+    SingleVariableSuccessors,
 };
 
 /// A helper alias which allows us to use [`ReachabilityComputation`] as shorthand for
@@ -74,6 +120,14 @@ impl<T: Algorithm<ReachabilityConfig, ReachabilityState, GraphColoredVertices> +
 /// A type alias for the recommended forward reachability configuration using saturation.
 pub type ForwardReachability = ReachabilityComputation<IterativeUnion<SaturationSuccessors>>;
 
+/// A type alias for a forward reachability procedure using saturation that first explores
+/// the most recently productive variable (see [`AdaptiveSaturationSuccessors`]).
+///
+/// The result is the same as for [`ForwardReachability`], but the schedule can be faster
+/// on some models.
+pub type ForwardReachabilityAdaptive =
+    ReachabilityComputation<IterativeUnion<AdaptiveSaturationSuccessors>>;
+
 /// A type alias for a forward reachability procedure that always explores
 /// the graph in the BFS order.
 ///
@@ -95,6 +149,108 @@ pub type BackwardReachability = ReachabilityComputation<IterativeUnion<Saturatio
 /// specific order of computation.
 pub type BackwardReachabilityBfs = ReachabilityComputation<IterativeUnion<BfsPredecessors>>;
 
+/// A type alias for a forward reachability procedure using saturation that only explores
+/// the variable updates allowed by [`ReachabilityConfig::transition_guard`] (see
+/// [`GuardedSaturationSuccessors`]).
+///
+/// Without a guard, the result is the same as for [`ForwardReachability`].
+pub type ForwardReachabilityGuarded =
+    ReachabilityComputation<IterativeUnion<GuardedSaturationSuccessors>>;
+
+/// A type alias for a forward reachability procedure where the step operator is selected
+/// at runtime using [`ReachabilityConfig::strategy`].
+///
+/// The results are the same as for [`ForwardReachability`] or [`ForwardReachabilityBfs`]
+/// (depending on the strategy). This is useful for tools that configure the algorithm
+/// from user input.
+pub type ForwardReachabilityDyn = ReachabilityComputation<IterativeUnion<DynSuccessors>>;
+
+/// A type alias for a backward reachability procedure where the step operator is selected
+/// at runtime using [`ReachabilityConfig::strategy`].
+///
+/// The results are the same as for [`BackwardReachability`] or [`BackwardReachabilityBfs`]
+/// (depending on the strategy). This is useful for tools that configure the algorithm
+/// from user input.
+pub type BackwardReachabilityDyn = ReachabilityComputation<IterativeUnion<DynPredecessors>>;
+
+/// Compute the states of the `graph` that can eventually reach the `target` set
+/// (including the `target` itself).
+///
+/// This is just [`BackwardReachability`] from `target`, but it is the tool of choice for
+/// questions like "which states eventually reach this attractor?". For parametrized networks,
+/// the result is evaluated for each color separately, i.e., a state-color pair is in the result
+/// if the state can reach `target` using the transitions of that color.
+pub fn can_reach(
+    graph: &SymbolicAsyncGraph,
+    target: &GraphColoredVertices,
+) -> Cancellable<GraphColoredVertices> {
+    BackwardReachability::run(graph, target.clone())
+}
+
+/// Compute the states of the `graph` that can never reach the `target` set.
+///
+/// This is the complement of [`can_reach`] (for each color separately).
+pub fn cannot_reach(
+    graph: &SymbolicAsyncGraph,
+    target: &GraphColoredVertices,
+) -> Cancellable<GraphColoredVertices> {
+    Ok(graph
+        .mk_unit_colored_vertices()
+        .minus(&can_reach(graph, target)?))
+}
+
+/// Compute the direct successors of `set` in the `graph`, but only for the given `colors`.
+///
+/// This is useful when analyzing one parameter valuation (or a small group of valuations)
+/// at a time without building a restricted graph. Since transitions never change colors,
+/// the result is the same as `graph.post(set).intersect_colors(colors)`, but the input is
+/// restricted first to keep the intermediate BDDs small. In the same way, running any
+/// reachability procedure (e.g., [`ForwardReachability`]) from `set.intersect_colors(colors)`
+/// only ever explores the given `colors`.
+pub fn post_for_colors(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    colors: &GraphColors,
+) -> GraphColoredVertices {
+    graph.post(&set.intersect_colors(colors))
+}
+
+/// Compute the direct predecessors of `set` in the `graph`, but only for the given `colors`.
+///
+/// See also [`post_for_colors`].
+pub fn pre_for_colors(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    colors: &GraphColors,
+) -> GraphColoredVertices {
+    graph.pre(&set.intersect_colors(colors))
+}
+
+/// Compute the direct successors of `set` in the `graph` that lie within the `universe`.
+///
+/// The result covers all variables at once and is evaluated for each color separately, i.e.,
+/// a state-color pair is in the result if it belongs to the `universe` and it is a successor
+/// of some state of `set` using the transitions of that color. This is the one-step image
+/// used by reachability confined to a universe (see also [`post_for_colors`]).
+pub fn post_within(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    universe: &GraphColoredVertices,
+) -> GraphColoredVertices {
+    graph.post(set).intersect(universe)
+}
+
+/// Compute the direct predecessors of `set` in the `graph` that lie within the `universe`.
+///
+/// See also [`post_within`].
+pub fn pre_within(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    universe: &GraphColoredVertices,
+) -> GraphColoredVertices {
+    graph.pre(set).intersect(universe)
+}
+
 /// Used to reduce code repetition in various reachability-like algorithms.
 ///
 /// Implementors define a single step of a reachability procedure, which is then
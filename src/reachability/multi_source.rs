@@ -0,0 +1,80 @@
+use crate::reachability::{ReachabilityConfig, ReachabilityStep, SaturationSuccessors};
+use crate::{LogLabel, log_set};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use cancel_this::Cancellable;
+use log::debug;
+
+/// Compute the forward reachable set of each of the given `seeds`, such that `result[i]`
+/// is the set of states reachable from `seeds[i]`.
+///
+/// The results are equivalent to running [`ForwardReachability`](crate::reachability::ForwardReachability)
+/// for each seed independently, but the work is shared between seeds: as soon as the reachable
+/// set of `seeds[i]` contains the whole `seeds[j]` (for `j < i`), the already computed
+/// `result[j]` is added to it directly. For parametrized networks, this is evaluated for each
+/// color separately. To keep the overhead low, the previous seeds are only checked when newly
+/// discovered states hit one of them.
+///
+/// The `config` is typically just a
+/// [`SymbolicAsyncGraph`](biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph),
+/// but a full [`ReachabilityConfig`] can be used as well.
+///
+/// This is the preferred way to screen many seeds at once. Note that the underlying BDD library
+/// does not keep an operation cache between individual BDD operations, so there is no cache
+/// that separate runs could share; the savings come only from reusing the reachable sets.
+pub fn multi_source_forward(
+    config: impl Into<ReachabilityConfig>,
+    seeds: &[GraphColoredVertices],
+) -> Cancellable<Vec<GraphColoredVertices>> {
+    let config = config.into();
+    let mut results: Vec<GraphColoredVertices> = Vec::with_capacity(seeds.len());
+    for (i, seed) in seeds.iter().enumerate() {
+        // The parts of the previous seeds (per color) whose results were not reused yet.
+        let mut pending = seeds[..i].to_vec();
+        let mut pending_union = union_all(&config, &pending);
+        let mut reach = seed.clone();
+        let mut new_states = seed.clone();
+        loop {
+            // A previous seed can only become covered once a new state hits it.
+            while !new_states.intersect(&pending_union).is_empty() {
+                let mut added = config.graph.mk_empty_colored_vertices();
+                for (j, part) in pending.iter_mut().enumerate() {
+                    let missing_colors = part.minus(&reach).colors();
+                    let covered_colors = part.minus_colors(&missing_colors).colors();
+                    if covered_colors.is_empty() {
+                        continue;
+                    }
+                    debug!(
+                        "{}Seed {i} reuses reachable set of seed {j}.",
+                        LogLabel(&config.label)
+                    );
+                    let reused = results[j].intersect_colors(&covered_colors).minus(&reach);
+                    reach = reach.union(&reused);
+                    added = added.union(&reused);
+                    *part = part.minus_colors(&covered_colors);
+                }
+                pending_union = union_all(&config, &pending);
+                new_states = added;
+            }
+
+            new_states = SaturationSuccessors::step(&config, &reach)?;
+            if new_states.is_empty() {
+                break;
+            }
+            reach = reach.union(&new_states);
+        }
+        debug!(
+            "{}Reachable set of seed {i} computed ({}).",
+            LogLabel(&config.label),
+            log_set(&reach)
+        );
+        results.push(reach);
+    }
+    Ok(results)
+}
+
+/// The union of all `sets`.
+fn union_all(config: &ReachabilityConfig, sets: &[GraphColoredVertices]) -> GraphColoredVertices {
+    sets.iter()
+        .fold(config.graph.mk_empty_colored_vertices(), |a, b| a.union(b))
+}
@@ -0,0 +1,49 @@
+use crate::reachability::{ForwardReachability, ReachabilityConfig};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use cancel_this::{Cancellable, is_cancelled};
+use computation_process::Algorithm;
+
+/// Compute the forward reachable set of `initial` by exploring one state-color pair
+/// at a time (a naive explicit-state search).
+///
+/// Only the [`ReachabilityConfig::active_variables`] are updated and the other settings
+/// (e.g., limits) are ignored. This is only practical for very small graphs, but it does not
+/// share any logic with the symbolic procedures, so it can be used as a reference
+/// (see [`verify_against_naive`]).
+pub fn reach_forward_naive<T: Into<ReachabilityConfig>>(
+    config: T,
+    initial: GraphColoredVertices,
+) -> Cancellable<GraphColoredVertices> {
+    let config = config.into();
+    let mut visited = initial.clone();
+    let mut frontier = initial;
+    while !frontier.is_empty() {
+        is_cancelled!()?;
+        let state = frontier.pick_singleton();
+        frontier = frontier.minus(&state);
+        for var in &config.active_variables {
+            let successor = config.graph.var_post(*var, &state);
+            if !successor.is_empty() && !successor.is_subset(&visited) {
+                visited = visited.union(&successor);
+                frontier = frontier.union(&successor);
+            }
+        }
+    }
+    Ok(visited)
+}
+
+/// Check that the symbolic [`ForwardReachability`] of `initial` is equal to the result of
+/// [`reach_forward_naive`].
+///
+/// This is a cross-validation tool intended for small graphs (e.g., in tests or debugging
+/// sessions); the naive search is exponentially slower on large graphs.
+pub fn verify_against_naive<T: Into<ReachabilityConfig>>(
+    config: T,
+    initial: GraphColoredVertices,
+) -> Cancellable<bool> {
+    let config = config.into();
+    let symbolic = ForwardReachability::run(config.clone(), initial.clone())?;
+    let naive = reach_forward_naive(config, initial)?;
+    Ok(symbolic == naive)
+}
@@ -0,0 +1,51 @@
+use crate::reachability::{
+    IterativeUnion, ReachabilityConfig, ReachabilityState, SaturationSuccessors,
+};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use computation_process::{Completable, Computable, ComputationStep};
+
+/// A forward reachability procedure (using saturation) that never enters the given `avoid` set.
+///
+/// The result is the set of states that are reachable from the initial set using paths that
+/// never touch `avoid`. This is implemented by confining the procedure to the complement of
+/// `avoid` (see [`ReachabilityConfig::with_universe`]), so it composes with any universe
+/// restriction that is already present in the config. The initial states in `avoid` are
+/// removed as well, i.e., if the whole initial set is inside `avoid`, the result is empty.
+/// For parametrized networks, `avoid` can be different for each color.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForwardReachabilityAvoiding {
+    config: ReachabilityConfig,
+    state: ReachabilityState,
+}
+
+impl ForwardReachabilityAvoiding {
+    /// Create a new reachability procedure starting in `initial` that never enters `avoid`.
+    ///
+    /// The `config` is typically just a
+    /// [`SymbolicAsyncGraph`](biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph),
+    /// but a full [`ReachabilityConfig`] can be used as well.
+    pub fn new(
+        config: impl Into<ReachabilityConfig>,
+        initial: GraphColoredVertices,
+        avoid: &GraphColoredVertices,
+    ) -> ForwardReachabilityAvoiding {
+        let config = config.into();
+        let allowed = config.graph.mk_unit_colored_vertices().minus(avoid);
+        ForwardReachabilityAvoiding {
+            config: config.with_universe(&allowed),
+            state: ReachabilityState::from(initial.minus(avoid)),
+        }
+    }
+
+    /// The current (partial) state of the reachability procedure.
+    pub fn state(&self) -> &ReachabilityState {
+        &self.state
+    }
+}
+
+impl Computable<GraphColoredVertices> for ForwardReachabilityAvoiding {
+    fn try_compute(&mut self) -> Completable<GraphColoredVertices> {
+        IterativeUnion::<SaturationSuccessors>::step(&self.config, &mut self.state)
+    }
+}
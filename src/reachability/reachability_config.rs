@@ -1,8 +1,15 @@
 use biodivine_lib_param_bn::VariableId;
-use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
 use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 /// A "flat" configuration object for various reachability problems.
+///
+/// The configuration is created once per computation and already serves as its shared
+/// context: the variable set is collected when the config is created, and the step operators
+/// only iterate over [`ReachabilityConfig::active_variables`] (the graph itself is cheap to
+/// clone). Hence, the steps never re-collect the network variables.
 #[derive(Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReachabilityConfig {
@@ -17,6 +24,10 @@ pub struct ReachabilityConfig {
     /// The set of variables that can be updated by the reachability procedure (default:
     /// all variables).
     ///
+    /// The step operators only explore the transitions of these variables (the transitions
+    /// of other variables are never computed). If the set is empty, the result of
+    /// reachability is the initial set. See also [`ReachabilityConfig::with_active_variables`].
+    ///
     /// # Panics
     ///
     /// The procedure is allowed to panic if this set contains variables not valid in
@@ -28,11 +39,196 @@ pub struct ReachabilityConfig {
     /// Note that the definition of "iteration" can depend on the chosen reachability operator.
     pub max_iterations: usize,
     /// Cancel the procedure if the symbolic representation exceeds the given amount of BDD nodes
-    /// (default: `usize::MAX`).
+    /// (default: `usize::MAX`). See also [`ReachabilityConfig::max_symbolic_size`].
+    ///
+    /// The procedure then fails with a [`Cancelled`](cancel_this::Cancelled) error whose
+    /// cause is `"ReachabilityConfig::max_symbolic_size"`. The partial result remains available
+    /// in the state of the computation (see
+    /// [`ReachabilityState`](crate::reachability::ReachabilityState)). For reachability, it is
+    /// a subset of the true reachable set, and the caller can use it to continue with
+    /// a different strategy.
     ///
     /// Note: In the future, this could be replaced by a global "symbolic size" cancellation
     /// trigger, but this will likely rely on direct support from the BDD library.
     pub max_symbolic_size: usize,
+    /// The step operator used by the runtime-dispatched reachability procedures
+    /// ([`ForwardReachabilityDyn`](crate::reachability::ForwardReachabilityDyn) and
+    /// [`BackwardReachabilityDyn`](crate::reachability::BackwardReachabilityDyn)) (default:
+    /// [`ReachabilityStrategy::Saturation`]).
+    ///
+    /// Procedures with a statically chosen step operator ignore this setting.
+    pub strategy: ReachabilityStrategy,
+    /// An optional callback that is periodically notified about the current reachable set
+    /// (default: `None`). See [`ReachabilityConfig::on_progress`].
+    ///
+    /// The callback is not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub progress: Option<ProgressCallback>,
+    /// The variable that most recently produced new states in
+    /// [`AdaptiveSaturationSuccessors`](crate::reachability::AdaptiveSaturationSuccessors).
+    ///
+    /// This only influences the order in which variables are explored, not the result.
+    /// The hint belongs to the computation that owns this config (see [`SaturationHint`]).
+    /// It is not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub saturation_hint: SaturationHint,
+    /// An optional tracker of the largest symbolic size (BDD nodes) of the intermediate
+    /// sets observed by the step operators (default: `None`). See
+    /// [`ReachabilityConfig::track_peak_symbolic_size`].
+    ///
+    /// The tracker is not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub peak_tracker: Option<PeakTracker>,
+    /// An optional guard which forbids some variable updates (default: `None`). See
+    /// [`ReachabilityConfig::with_transition_guard`].
+    ///
+    /// Only [`GuardedSaturationSuccessors`](crate::reachability::GuardedSaturationSuccessors)
+    /// respects the guard; other step operators ignore it. The guard is not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub transition_guard: Option<TransitionGuard>,
+    /// The variable updated by
+    /// [`SingleVariableSuccessors`](crate::reachability::SingleVariableSuccessors) (default:
+    /// `None`). See [`ReachabilityConfig::with_step_variable`].
+    ///
+    /// Other step operators ignore this setting.
+    pub step_variable: Option<VariableId>,
+    /// An optional label of this run which is used as a prefix of all log messages
+    /// (default: `None`). This is useful to distinguish logs of concurrent runs.
+    pub label: Option<String>,
+}
+
+/// The step operator selected at runtime by [`ReachabilityConfig::strategy`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReachabilityStrategy {
+    /// Use saturation, i.e., [`SaturationSuccessors`](crate::reachability::SaturationSuccessors)
+    /// or [`SaturationPredecessors`](crate::reachability::SaturationPredecessors) (default).
+    #[default]
+    Saturation,
+    /// Explore the graph in the BFS order, i.e.,
+    /// [`BfsSuccessors`](crate::reachability::BfsSuccessors) or
+    /// [`BfsPredecessors`](crate::reachability::BfsPredecessors).
+    Bfs,
+}
+
+/// A callback that observes the intermediate results of a reachability procedure.
+///
+/// The callback only receives an immutable reference to the current set, so it cannot
+/// influence the computation. It is shared between all clones of the parent
+/// [`ReachabilityConfig`].
+#[derive(Clone)]
+pub struct ProgressCallback {
+    interval: usize,
+    callback: Arc<Mutex<ProgressFn>>,
+}
+
+type ProgressFn = dyn FnMut(&GraphColoredVertices) + Send;
+
+impl ProgressCallback {
+    /// Create a new callback that is invoked every `interval` iterations (an interval
+    /// of zero is treated as one).
+    pub fn new<F>(interval: usize, callback: F) -> ProgressCallback
+    where
+        F: FnMut(&GraphColoredVertices) + Send + 'static,
+    {
+        ProgressCallback {
+            interval: interval.max(1),
+            callback: Arc::new(Mutex::new(callback)),
+        }
+    }
+
+    /// Invoke the callback if the `iteration` matches the configured interval.
+    pub(crate) fn notify(&self, iteration: usize, set: &GraphColoredVertices) {
+        if iteration.is_multiple_of(self.interval) {
+            // If the callback panicked before, we simply stop notifying it.
+            if let Ok(mut callback) = self.callback.lock() {
+                (*callback)(set);
+            }
+        }
+    }
+}
+
+/// A predicate which decides whether a variable can be updated from the given value
+/// (see [`ReachabilityConfig::transition_guard`]).
+///
+/// For example, a guard returning `false` for `(x, true)` forbids `x` to turn off.
+/// The guard is shared between all clones of the parent [`ReachabilityConfig`].
+#[derive(Clone)]
+pub struct TransitionGuard {
+    guard: Arc<dyn Fn(VariableId, bool) -> bool + Send + Sync>,
+}
+
+impl TransitionGuard {
+    /// Create a new guard from a predicate over `(variable, value before the update)`.
+    pub fn new<F>(guard: F) -> TransitionGuard
+    where
+        F: Fn(VariableId, bool) -> bool + Send + Sync + 'static,
+    {
+        TransitionGuard {
+            guard: Arc::new(guard),
+        }
+    }
+
+    /// Check whether `variable` can be updated in states where its value is `from_value`.
+    pub fn allows(&self, variable: VariableId, from_value: bool) -> bool {
+        (self.guard)(variable, from_value)
+    }
+}
+
+/// A variable that is explored first by adaptive saturation (see
+/// [`ReachabilityConfig::saturation_hint`]).
+///
+/// The hint is never shared: a clone of the parent [`ReachabilityConfig`] starts without
+/// a hint. Since every computation owns its config, concurrent computations created from
+/// the same config cannot influence each other's schedule.
+#[derive(Debug, Default)]
+pub struct SaturationHint {
+    /// Index of the hinted variable plus one (zero means no hint).
+    variable: AtomicUsize,
+}
+
+impl Clone for SaturationHint {
+    fn clone(&self) -> Self {
+        SaturationHint::default()
+    }
+}
+
+impl SaturationHint {
+    /// The currently hinted variable (if any).
+    pub fn get(&self) -> Option<VariableId> {
+        match self.variable.load(Ordering::Relaxed) {
+            0 => None,
+            index => Some(VariableId::from_index(index - 1)),
+        }
+    }
+
+    /// Update the hinted variable.
+    pub fn set(&self, variable: VariableId) {
+        self.variable
+            .store(variable.to_index() + 1, Ordering::Relaxed);
+    }
+}
+
+/// Records the largest symbolic size (BDD nodes) of the observed sets (see
+/// [`ReachabilityConfig::peak_tracker`]).
+///
+/// The tracker is shared between all clones of the parent config, so the peak can be read
+/// using a clone of the config after the computation is finished.
+#[derive(Clone, Debug, Default)]
+pub struct PeakTracker {
+    peak: Arc<AtomicUsize>,
+}
+
+impl PeakTracker {
+    /// The largest symbolic size observed so far (zero if nothing was observed).
+    pub fn get(&self) -> usize {
+        self.peak.load(Ordering::Relaxed)
+    }
+
+    /// Update the peak using the symbolic size of the given `set`.
+    pub fn observe(&self, set: &GraphColoredVertices) {
+        self.peak.fetch_max(set.symbolic_size(), Ordering::Relaxed);
+    }
 }
 
 impl From<SymbolicAsyncGraph> for ReachabilityConfig {
@@ -56,6 +252,114 @@ impl ReachabilityConfig {
             graph,
             max_iterations: usize::MAX,
             max_symbolic_size: usize::MAX,
+            strategy: ReachabilityStrategy::Saturation,
+            progress: None,
+            saturation_hint: SaturationHint::default(),
+            peak_tracker: None,
+            transition_guard: None,
+            step_variable: None,
+            label: None,
         }
     }
+
+    /// Register a callback that is invoked every `interval` iterations with the current
+    /// intermediate set of the reachability procedure.
+    ///
+    /// The callback can be used to report progress (e.g., using
+    /// [`GraphColoredVertices::exact_cardinality`] and
+    /// [`GraphColoredVertices::symbolic_size`]) without parsing log output. If no callback
+    /// is set, the procedure behaves exactly the same.
+    pub fn on_progress<F>(mut self, interval: usize, callback: F) -> ReachabilityConfig
+    where
+        F: FnMut(&GraphColoredVertices) + Send + 'static,
+    {
+        self.progress = Some(ProgressCallback::new(interval, callback));
+        self
+    }
+
+    /// Update the [`ReachabilityConfig::active_variables`] setting, such that only
+    /// the given `variables` can be updated.
+    pub fn with_active_variables(
+        mut self,
+        variables: impl IntoIterator<Item = VariableId>,
+    ) -> ReachabilityConfig {
+        self.active_variables = BTreeSet::from_iter(variables);
+        self
+    }
+
+    /// Confine the procedure to the given `universe` by restricting the
+    /// [`ReachabilityConfig::graph`] (see [`SymbolicAsyncGraph::restrict`]).
+    ///
+    /// After the restriction, all transitions that enter or leave the `universe` are ignored.
+    /// For example, reachability never leaves the `universe`, and trap computations (see
+    /// [`crate::trapping`]) ignore successors and predecessors outside of the `universe`.
+    /// Restricting to the unit set has no effect.
+    pub fn with_universe(mut self, universe: &GraphColoredVertices) -> ReachabilityConfig {
+        self.graph = self.graph.restrict(universe);
+        self
+    }
+
+    /// Update the [`ReachabilityConfig::max_symbolic_size`] setting.
+    pub fn max_symbolic_size(mut self, limit: usize) -> ReachabilityConfig {
+        self.max_symbolic_size = limit;
+        self
+    }
+
+    /// Forbid the variable updates for which the `guard` returns `false` (see
+    /// [`TransitionGuard`]).
+    ///
+    /// The guard is only respected by
+    /// [`GuardedSaturationSuccessors`](crate::reachability::GuardedSaturationSuccessors)
+    /// (e.g., [`ForwardReachabilityGuarded`](crate::reachability::ForwardReachabilityGuarded)).
+    pub fn with_transition_guard<F>(mut self, guard: F) -> ReachabilityConfig
+    where
+        F: Fn(VariableId, bool) -> bool + Send + Sync + 'static,
+    {
+        self.transition_guard = Some(TransitionGuard::new(guard));
+        self
+    }
+
+    /// Update the [`ReachabilityConfig::step_variable`] setting, such that
+    /// [`SingleVariableSuccessors`](crate::reachability::SingleVariableSuccessors) only
+    /// updates the given `variable`.
+    pub fn with_step_variable(mut self, variable: VariableId) -> ReachabilityConfig {
+        self.step_variable = Some(variable);
+        self
+    }
+
+    /// Update the [`ReachabilityConfig::strategy`] setting.
+    pub fn strategy(mut self, strategy: ReachabilityStrategy) -> ReachabilityConfig {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Enable the [`ReachabilityConfig::peak_tracker`], such that the largest symbolic size
+    /// of the intermediate sets can be read using
+    /// [`ReachabilityConfig::peak_symbolic_size`] after the computation.
+    pub fn track_peak_symbolic_size(mut self) -> ReachabilityConfig {
+        self.peak_tracker = Some(PeakTracker::default());
+        self
+    }
+
+    /// The largest symbolic size (BDD nodes) of the intermediate sets observed so far, or
+    /// zero if the [`ReachabilityConfig::peak_tracker`] is not enabled.
+    pub fn peak_symbolic_size(&self) -> usize {
+        self.peak_tracker
+            .as_ref()
+            .map(PeakTracker::get)
+            .unwrap_or(0)
+    }
+
+    /// Record the symbolic size of `set` in the [`ReachabilityConfig::peak_tracker`] (if any).
+    pub(crate) fn observe_peak(&self, set: &GraphColoredVertices) {
+        if let Some(tracker) = &self.peak_tracker {
+            tracker.observe(set);
+        }
+    }
+
+    /// Update the [`ReachabilityConfig::label`] setting.
+    pub fn label(mut self, label: impl Into<String>) -> ReachabilityConfig {
+        self.label = Some(label.into());
+        self
+    }
 }
@@ -1,5 +1,11 @@
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use num_bigint::BigUint;
 
+/// The state of a reachability-like computation (reachability, trimming, or trap set
+/// computation), i.e., the current set and the number of performed iterations.
+///
+/// This can be accessed using [`Stateful::state`](computation_process::Stateful::state) to
+/// monitor a running computation.
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReachabilityState {
@@ -27,3 +33,15 @@ impl From<ReachabilityState> for GraphColoredVertices {
         value.set
     }
 }
+
+impl ReachabilityState {
+    /// The exact number of (colored) vertices in the current set.
+    pub fn current_cardinality(&self) -> BigUint {
+        self.set.exact_cardinality()
+    }
+
+    /// The number of BDD nodes used to represent the current set.
+    pub fn current_symbolic_size(&self) -> usize {
+        self.set.symbolic_size()
+    }
+}
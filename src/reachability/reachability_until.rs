@@ -0,0 +1,85 @@
+use crate::reachability::{
+    IterativeUnion, ReachabilityConfig, ReachabilityState, SaturationSuccessors,
+};
+use crate::{LogLabel, log_set};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, GraphColors};
+use computation_process::{Completable, Computable, ComputationStep};
+use log::debug;
+
+/// A forward reachability procedure (using saturation) that stops as soon as the reachable
+/// set intersects the given `target`.
+///
+/// For parametrized networks, the search stops separately for each color: once the target
+/// is reached in some colors, the reachable set no longer grows in these colors, but the
+/// search continues in the remaining colors.
+///
+/// The result is a pair `(set, reached)`, where `reached` are the colors in which the target
+/// is reachable. In these colors, `set` is a partial forward reachable set that intersects
+/// `target`. Every state in `set` is reachable from the initial set through a path within
+/// `set`, hence `set` contains a witness path from the initial set to the `target`. In all
+/// other colors, `set` is the full forward reachable set (which does not intersect `target`).
+///
+/// This is much faster than [`ForwardReachability`](crate::reachability::ForwardReachability)
+/// for yes/no reachability questions when the target is reachable.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForwardReachabilityUntil {
+    config: ReachabilityConfig,
+    state: ReachabilityState,
+    target: GraphColoredVertices,
+}
+
+impl ForwardReachabilityUntil {
+    /// Create a new reachability procedure starting in `initial` that stops once
+    /// it reaches `target`.
+    ///
+    /// The `config` is typically just a
+    /// [`SymbolicAsyncGraph`](biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph),
+    /// but a full [`ReachabilityConfig`] can be used as well.
+    pub fn new(
+        config: impl Into<ReachabilityConfig>,
+        initial: GraphColoredVertices,
+        target: GraphColoredVertices,
+    ) -> ForwardReachabilityUntil {
+        ForwardReachabilityUntil {
+            config: config.into(),
+            state: ReachabilityState::from(initial),
+            target,
+        }
+    }
+
+    /// The current (partial) state of the reachability procedure.
+    pub fn state(&self) -> &ReachabilityState {
+        &self.state
+    }
+}
+
+impl Computable<(GraphColoredVertices, GraphColors)> for ForwardReachabilityUntil {
+    fn try_compute(&mut self) -> Completable<(GraphColoredVertices, GraphColors)> {
+        let reached = self.state.set.intersect(&self.target).colors();
+        if self.state.set.minus_colors(&reached).is_empty() {
+            debug!(
+                "{}[iteration:{}] Target reached with ({}).",
+                LogLabel(&self.config.label),
+                self.state.iteration,
+                log_set(&self.state.set)
+            );
+            return Ok((self.state.set.clone(), reached));
+        }
+
+        // Stop the search in the colors that reached the target.
+        let unit = self.config.graph.unit_colored_vertices();
+        if !unit.colors().intersect(&reached).is_empty() {
+            debug!(
+                "{}[iteration:{}] Target reached in {} colors.",
+                LogLabel(&self.config.label),
+                self.state.iteration,
+                reached.exact_cardinality()
+            );
+            self.config.graph = self.config.graph.restrict(&unit.minus_colors(&reached));
+        }
+
+        let result = IterativeUnion::<SaturationSuccessors>::step(&self.config, &mut self.state)?;
+        Ok((result, reached))
+    }
+}
@@ -0,0 +1,60 @@
+use crate::reachability::{BackwardReachability, ForwardReachability, ReachabilityConfig};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use cancel_this::Cancellable;
+use computation_process::Algorithm;
+use num_bigint::BigUint;
+
+/// The result of a reachability procedure that only supports membership queries and counting.
+///
+/// The set is still stored symbolically, but the narrow API avoids accidental enumeration
+/// of (potentially huge) reachable sets when only membership checks are needed. The
+/// underlying set is available using [`ReachableSet::into_set`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReachableSet {
+    set: GraphColoredVertices,
+}
+
+impl From<GraphColoredVertices> for ReachableSet {
+    fn from(value: GraphColoredVertices) -> Self {
+        ReachableSet { set: value }
+    }
+}
+
+impl ReachableSet {
+    /// Compute the set of states reachable from `initial` (see [`ForwardReachability`]).
+    pub fn forward<T: Into<ReachabilityConfig>>(
+        config: T,
+        initial: GraphColoredVertices,
+    ) -> Cancellable<ReachableSet> {
+        ForwardReachability::run(config, initial).map(ReachableSet::from)
+    }
+
+    /// Compute the set of states that can reach `initial` (see [`BackwardReachability`]).
+    pub fn backward<T: Into<ReachabilityConfig>>(
+        config: T,
+        initial: GraphColoredVertices,
+    ) -> Cancellable<ReachableSet> {
+        BackwardReachability::run(config, initial).map(ReachableSet::from)
+    }
+
+    /// Check whether the `states` intersect this set.
+    ///
+    /// For a single state (of a single color), this is a membership test. For larger sets,
+    /// the result is `true` if at least one state-color pair is reachable.
+    pub fn contains(&self, states: &GraphColoredVertices) -> bool {
+        !self.set.intersect(states).is_empty()
+    }
+
+    /// The number of state-color pairs in this set (see
+    /// [`GraphColoredVertices::exact_cardinality`]).
+    pub fn cardinality(&self) -> BigUint {
+        self.set.exact_cardinality()
+    }
+
+    /// Unwrap the underlying symbolic set.
+    pub fn into_set(self) -> GraphColoredVertices {
+        self.set
+    }
+}
@@ -1,5 +1,5 @@
-use crate::log_set;
-use crate::reachability::{ReachabilityConfig, ReachabilityStep};
+use crate::reachability::{ReachabilityConfig, ReachabilityStep, ReachabilityStrategy};
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
 use cancel_this::{Cancellable, is_cancelled};
@@ -21,6 +21,37 @@ pub struct SaturationSuccessors;
 /// the current reachable set and return those predecessors (or empty set otherwise).
 pub struct SaturationPredecessors;
 
+/// Same as [`SaturationSuccessors`], but first tries the variable that most recently produced
+/// new states (stored in [`ReachabilityConfig::saturation_hint`]).
+///
+/// The resulting fixed point is the same as for [`SaturationSuccessors`]; only the order
+/// in which the variables are explored can change.
+pub struct AdaptiveSaturationSuccessors;
+
+/// Same as [`SaturationSuccessors`], but only uses the variable updates allowed by
+/// the [`ReachabilityConfig::transition_guard`] (if any).
+///
+/// For each variable, the updates are only computed from the states where the current value
+/// of the variable is allowed by the guard.
+pub struct GuardedSaturationSuccessors;
+
+/// Computes the direct successors of the current reachable set obtained by updating only
+/// the [`ReachabilityConfig::step_variable`], excluding values that are already in
+/// the reachable set.
+///
+/// If the variable is not set or not in [`ReachabilityConfig::active_variables`], the result
+/// is empty. This operator can be used as a building block of custom (e.g., saturation)
+/// schedules.
+pub struct SingleVariableSuccessors;
+
+/// Computes successors using the step operator selected at runtime by
+/// [`ReachabilityConfig::strategy`] (i.e., [`SaturationSuccessors`] or [`BfsSuccessors`]).
+pub struct DynSuccessors;
+
+/// Computes predecessors using the step operator selected at runtime by
+/// [`ReachabilityConfig::strategy`] (i.e., [`SaturationPredecessors`] or [`BfsPredecessors`]).
+pub struct DynPredecessors;
+
 impl ReachabilityStep for BfsSuccessors {
     fn step(
         context: &ReachabilityConfig,
@@ -34,7 +65,11 @@ impl ReachabilityStep for BfsSuccessors {
                 is_cancelled!()?;
                 post = post.union(&var_successors);
 
-                trace!("[{var}] Successors found ({}).", log_set(&post));
+                trace!(
+                    "{}[{var}] Successors found ({}).",
+                    LogLabel(&context.label),
+                    log_set(&post)
+                );
             }
         }
         Ok(post)
@@ -53,7 +88,11 @@ impl ReachabilityStep for BfsPredecessors {
             if !var_predecessors.is_empty() {
                 pre = pre.union(&var_predecessors);
 
-                trace!("[{var}] Predecessors found ({}).", log_set(&pre));
+                trace!(
+                    "{}[{var}] Predecessors found ({}).",
+                    LogLabel(&context.label),
+                    log_set(&pre)
+                );
             }
         }
         Ok(pre)
@@ -69,7 +108,42 @@ impl ReachabilityStep for SaturationSuccessors {
             is_cancelled!()?;
             let step = context.graph.var_post_out(*var, state);
             if !step.is_empty() {
-                trace!("[{var}] Successors found ({}).", log_set(&step));
+                trace!(
+                    "{}[{var}] Successors found ({}).",
+                    LogLabel(&context.label),
+                    log_set(&step)
+                );
+                return Ok(step);
+            }
+        }
+
+        Ok(context.graph.mk_empty_colored_vertices())
+    }
+}
+
+impl ReachabilityStep for GuardedSaturationSuccessors {
+    fn step(
+        context: &ReachabilityConfig,
+        state: &GraphColoredVertices,
+    ) -> Cancellable<GraphColoredVertices> {
+        let Some(guard) = &context.transition_guard else {
+            return SaturationSuccessors::step(context, state);
+        };
+        for var in context.active_variables.iter().rev() {
+            is_cancelled!()?;
+            let sources = match (guard.allows(*var, true), guard.allows(*var, false)) {
+                (true, true) => state.clone(),
+                (true, false) => state.intersect(&context.graph.fix_network_variable(*var, true)),
+                (false, true) => state.intersect(&context.graph.fix_network_variable(*var, false)),
+                (false, false) => continue,
+            };
+            let step = context.graph.var_post_out(*var, &sources).minus(state);
+            if !step.is_empty() {
+                trace!(
+                    "{}[{var}] Guarded successors found ({}).",
+                    LogLabel(&context.label),
+                    log_set(&step)
+                );
                 return Ok(step);
             }
         }
@@ -87,7 +161,41 @@ impl ReachabilityStep for SaturationPredecessors {
             is_cancelled!()?;
             let step = context.graph.var_pre_out(*var, state);
             if !step.is_empty() {
-                trace!("[{var}] Predecessors found ({}).", log_set(&step));
+                trace!(
+                    "{}[{var}] Predecessors found ({}).",
+                    LogLabel(&context.label),
+                    log_set(&step)
+                );
+                return Ok(step);
+            }
+        }
+
+        Ok(context.graph.mk_empty_colored_vertices())
+    }
+}
+
+impl ReachabilityStep for AdaptiveSaturationSuccessors {
+    fn step(
+        context: &ReachabilityConfig,
+        state: &GraphColoredVertices,
+    ) -> Cancellable<GraphColoredVertices> {
+        let hint = context
+            .saturation_hint
+            .get()
+            .filter(|var| context.active_variables.contains(var));
+        let schedule = hint
+            .into_iter()
+            .chain(context.active_variables.iter().rev().copied());
+        for var in schedule {
+            is_cancelled!()?;
+            let step = context.graph.var_post_out(var, state);
+            if !step.is_empty() {
+                trace!(
+                    "{}[{var}] Successors found ({}).",
+                    LogLabel(&context.label),
+                    log_set(&step)
+                );
+                context.saturation_hint.set(var);
                 return Ok(step);
             }
         }
@@ -95,3 +203,51 @@ impl ReachabilityStep for SaturationPredecessors {
         Ok(context.graph.mk_empty_colored_vertices())
     }
 }
+
+impl ReachabilityStep for SingleVariableSuccessors {
+    fn step(
+        context: &ReachabilityConfig,
+        state: &GraphColoredVertices,
+    ) -> Cancellable<GraphColoredVertices> {
+        let Some(var) = context
+            .step_variable
+            .filter(|var| context.active_variables.contains(var))
+        else {
+            return Ok(context.graph.mk_empty_colored_vertices());
+        };
+        is_cancelled!()?;
+        let step = context.graph.var_post_out(var, state);
+        if !step.is_empty() {
+            trace!(
+                "{}[{var}] Successors found ({}).",
+                LogLabel(&context.label),
+                log_set(&step)
+            );
+        }
+        Ok(step)
+    }
+}
+
+impl ReachabilityStep for DynSuccessors {
+    fn step(
+        context: &ReachabilityConfig,
+        state: &GraphColoredVertices,
+    ) -> Cancellable<GraphColoredVertices> {
+        match context.strategy {
+            ReachabilityStrategy::Saturation => SaturationSuccessors::step(context, state),
+            ReachabilityStrategy::Bfs => BfsSuccessors::step(context, state),
+        }
+    }
+}
+
+impl ReachabilityStep for DynPredecessors {
+    fn step(
+        context: &ReachabilityConfig,
+        state: &GraphColoredVertices,
+    ) -> Cancellable<GraphColoredVertices> {
+        match context.strategy {
+            ReachabilityStrategy::Saturation => SaturationPredecessors::step(context, state),
+            ReachabilityStrategy::Bfs => BfsPredecessors::step(context, state),
+        }
+    }
+}
@@ -2,21 +2,33 @@
 //!
 //! See `llm_example_network.rs` for the complete documentation of the test network structure.
 
+use crate::build::from_transitions;
 use crate::reachability::{
-    BfsPredecessors, BfsSuccessors, IterativeUnion, ReachabilityComputation, ReachabilityConfig,
-    ReachabilityState, SaturationPredecessors, SaturationSuccessors,
+    BackwardReachability, BackwardReachabilityBfs, BackwardReachabilityDyn, BfsPredecessors,
+    BfsSuccessors, ForwardReachability, ForwardReachabilityAdaptive, ForwardReachabilityAvoiding,
+    ForwardReachabilityBfs, ForwardReachabilityDyn, ForwardReachabilityGuarded,
+    ForwardReachabilityUntil, IterativeUnion, ReachabilityComputation, ReachabilityConfig,
+    ReachabilityState, ReachabilityStep, ReachabilityStrategy, ReachableSet,
+    SaturationPredecessors, SaturationSuccessors, SingleVariableSuccessors, can_reach,
+    cannot_reach, forward_with_changed_variables, multi_source_forward, post_for_colors,
+    post_within, pre_for_colors, pre_within, reach_forward_naive, verify_against_naive,
 };
-use crate::test_utils::llm_example_network::create_test_network;
 use crate::test_utils::llm_example_network::sets::{
     ALL_STATES, ATTRACTOR_1, ATTRACTOR_2, CAN_REACH_ATTR1, CAN_REACH_ATTR2, SOURCE_STATES,
     STRONG_BASIN_ATTR1, STRONG_BASIN_ATTR2, WEAK_BASIN,
 };
 use crate::test_utils::llm_example_network::states::*;
+use crate::test_utils::llm_example_network::{
+    create_parameterized_test_network, create_test_network,
+};
 use crate::test_utils::{init_logger, mk_state, mk_states};
+use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
-use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
 use cancel_this::Cancellable;
-use computation_process::{Algorithm, ComputationStep};
+use computation_process::{Algorithm, Computable, ComputationStep, Stateful};
+use num_bigint::BigUint;
+use std::collections::BTreeSet;
 
 // ========== Parametrized test helpers ==========
 
@@ -404,188 +416,809 @@ where
 }
 
 // ========== Tests for BFS forward algorithms ==========
-type ForwardReachabilityBfs = IterativeUnion<BfsSuccessors>;
+type ForwardBfsStep = IterativeUnion<BfsSuccessors>;
 
 #[test]
 fn test_reach_forward_from_empty_set_bfs() -> Cancellable<()> {
-    test_reach_forward_from_empty_set_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_from_empty_set_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_reach_forward_from_fixed_point_bfs() -> Cancellable<()> {
-    test_reach_forward_from_fixed_point_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_from_fixed_point_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_reach_forward_from_attractor_2_bfs() -> Cancellable<()> {
-    test_reach_forward_from_attractor_2_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_from_attractor_2_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_reach_forward_from_strong_basin_of_attractor_1_bfs() -> Cancellable<()> {
-    test_reach_forward_from_strong_basin_of_attractor_1_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_from_strong_basin_of_attractor_1_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_reach_forward_from_strong_basin_of_attractor_2_bfs() -> Cancellable<()> {
-    test_reach_forward_from_strong_basin_of_attractor_2_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_from_strong_basin_of_attractor_2_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_reach_forward_from_weak_basin_reaches_both_attractors_bfs() -> Cancellable<()> {
-    test_reach_forward_from_weak_basin_reaches_both_attractors_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_from_weak_basin_reaches_both_attractors_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_reach_forward_from_weak_basin_100_bfs() -> Cancellable<()> {
-    test_reach_forward_from_weak_basin_100_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_from_weak_basin_100_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_reach_forward_includes_initial_bfs() -> Cancellable<()> {
-    test_reach_forward_includes_initial_impl::<ForwardReachabilityBfs>()
+    test_reach_forward_includes_initial_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_basin_separation_bfs() -> Cancellable<()> {
-    test_basin_separation_impl::<ForwardReachabilityBfs>()
+    test_basin_separation_impl::<ForwardBfsStep>()
 }
 
 #[test]
 fn test_forward_reach_from_sources_covers_everything_bfs() -> Cancellable<()> {
-    test_forward_reach_from_sources_covers_everything_impl::<ForwardReachabilityBfs>()
+    test_forward_reach_from_sources_covers_everything_impl::<ForwardBfsStep>()
 }
 
 // ========== Tests for saturation forward algorithms ==========
-type ForwardReachability = IterativeUnion<SaturationSuccessors>;
+type ForwardSaturationStep = IterativeUnion<SaturationSuccessors>;
 
 #[test]
 fn test_reach_forward_from_empty_set_sat() -> Cancellable<()> {
-    test_reach_forward_from_empty_set_impl::<ForwardReachability>()
+    test_reach_forward_from_empty_set_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_forward_from_fixed_point_sat() -> Cancellable<()> {
-    test_reach_forward_from_fixed_point_impl::<ForwardReachability>()
+    test_reach_forward_from_fixed_point_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_forward_from_attractor_2_sat() -> Cancellable<()> {
-    test_reach_forward_from_attractor_2_impl::<ForwardReachability>()
+    test_reach_forward_from_attractor_2_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_forward_from_strong_basin_of_attractor_1_sat() -> Cancellable<()> {
-    test_reach_forward_from_strong_basin_of_attractor_1_impl::<ForwardReachability>()
+    test_reach_forward_from_strong_basin_of_attractor_1_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_forward_from_strong_basin_of_attractor_2_sat() -> Cancellable<()> {
-    test_reach_forward_from_strong_basin_of_attractor_2_impl::<ForwardReachability>()
+    test_reach_forward_from_strong_basin_of_attractor_2_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_forward_from_weak_basin_reaches_both_attractors_sat() -> Cancellable<()> {
-    test_reach_forward_from_weak_basin_reaches_both_attractors_impl::<ForwardReachability>()
+    test_reach_forward_from_weak_basin_reaches_both_attractors_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_forward_from_weak_basin_100_sat() -> Cancellable<()> {
-    test_reach_forward_from_weak_basin_100_impl::<ForwardReachability>()
+    test_reach_forward_from_weak_basin_100_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_forward_includes_initial_sat() -> Cancellable<()> {
-    test_reach_forward_includes_initial_impl::<ForwardReachability>()
+    test_reach_forward_includes_initial_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_basin_separation_sat() -> Cancellable<()> {
-    test_basin_separation_impl::<ForwardReachability>()
+    test_basin_separation_impl::<ForwardSaturationStep>()
 }
 
 #[test]
 fn test_forward_reach_from_sources_covers_everything_sat() -> Cancellable<()> {
-    test_forward_reach_from_sources_covers_everything_impl::<ForwardReachability>()
+    test_forward_reach_from_sources_covers_everything_impl::<ForwardSaturationStep>()
 }
 
 // ========== Tests for BFS backward algorithms ==========
-type BackwardReachabilityBfs = IterativeUnion<BfsPredecessors>;
+type BackwardBfsStep = IterativeUnion<BfsPredecessors>;
 
 #[test]
 fn test_reach_backward_from_empty_set_bfs() -> Cancellable<()> {
-    test_reach_backward_from_empty_set_impl::<BackwardReachabilityBfs>()
+    test_reach_backward_from_empty_set_impl::<BackwardBfsStep>()
 }
 
 #[test]
 fn test_reach_backward_to_fixed_point_bfs() -> Cancellable<()> {
-    test_reach_backward_to_fixed_point_impl::<BackwardReachabilityBfs>()
+    test_reach_backward_to_fixed_point_impl::<BackwardBfsStep>()
 }
 
 #[test]
 fn test_reach_backward_to_attractor_2_bfs() -> Cancellable<()> {
-    test_reach_backward_to_attractor_2_impl::<BackwardReachabilityBfs>()
+    test_reach_backward_to_attractor_2_impl::<BackwardBfsStep>()
 }
 
 #[test]
 fn test_reach_backward_from_single_state_in_cycle_bfs() -> Cancellable<()> {
-    test_reach_backward_from_single_state_in_cycle_impl::<BackwardReachabilityBfs>()
+    test_reach_backward_from_single_state_in_cycle_impl::<BackwardBfsStep>()
 }
 
 #[test]
 fn test_reach_backward_includes_initial_bfs() -> Cancellable<()> {
-    test_reach_backward_includes_initial_impl::<BackwardReachabilityBfs>()
+    test_reach_backward_includes_initial_impl::<BackwardBfsStep>()
 }
 
 // ========== Tests for saturation backward algorithms ==========
-type BackwardReachability = IterativeUnion<SaturationPredecessors>;
+type BackwardSaturationStep = IterativeUnion<SaturationPredecessors>;
 
 #[test]
 fn test_reach_backward_from_empty_set_sat() -> Cancellable<()> {
-    test_reach_backward_from_empty_set_impl::<BackwardReachability>()
+    test_reach_backward_from_empty_set_impl::<BackwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_backward_to_fixed_point_sat() -> Cancellable<()> {
-    test_reach_backward_to_fixed_point_impl::<BackwardReachability>()
+    test_reach_backward_to_fixed_point_impl::<BackwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_backward_to_attractor_2_sat() -> Cancellable<()> {
-    test_reach_backward_to_attractor_2_impl::<BackwardReachability>()
+    test_reach_backward_to_attractor_2_impl::<BackwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_backward_from_single_state_in_cycle_sat() -> Cancellable<()> {
-    test_reach_backward_from_single_state_in_cycle_impl::<BackwardReachability>()
+    test_reach_backward_from_single_state_in_cycle_impl::<BackwardSaturationStep>()
 }
 
 #[test]
 fn test_reach_backward_includes_initial_sat() -> Cancellable<()> {
-    test_reach_backward_includes_initial_impl::<BackwardReachability>()
+    test_reach_backward_includes_initial_impl::<BackwardSaturationStep>()
 }
 
 // ========== Tests for SCC (forward + backward combinations) ==========
 
 #[test]
 fn test_scc_via_forward_backward_intersection_bfs_bfs() -> Cancellable<()> {
-    test_scc_via_forward_backward_intersection_impl::<ForwardReachabilityBfs, BackwardReachabilityBfs>(
-    )
+    test_scc_via_forward_backward_intersection_impl::<ForwardBfsStep, BackwardBfsStep>()
 }
 
 #[test]
 fn test_scc_via_forward_backward_intersection_sat_sat() -> Cancellable<()> {
-    test_scc_via_forward_backward_intersection_impl::<ForwardReachability, BackwardReachability>()
+    test_scc_via_forward_backward_intersection_impl::<ForwardSaturationStep, BackwardSaturationStep>(
+    )
 }
 
 #[test]
 fn test_scc_via_forward_backward_intersection_bfs_sat() -> Cancellable<()> {
-    test_scc_via_forward_backward_intersection_impl::<ForwardReachabilityBfs, BackwardReachability>(
-    )
+    test_scc_via_forward_backward_intersection_impl::<ForwardBfsStep, BackwardSaturationStep>()
 }
 
 #[test]
 fn test_scc_via_forward_backward_intersection_sat_bfs() -> Cancellable<()> {
-    test_scc_via_forward_backward_intersection_impl::<ForwardReachability, BackwardReachabilityBfs>(
-    )
+    test_scc_via_forward_backward_intersection_impl::<ForwardSaturationStep, BackwardBfsStep>()
+}
+
+// ========== Tests for progress reporting ==========
+
+#[test]
+fn test_reach_forward_progress_callback() -> Cancellable<()> {
+    use std::sync::{Arc, Mutex};
+
+    init_logger();
+    let graph = create_test_network();
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let observed_copy = observed.clone();
+    let config = ReachabilityConfig::new(graph.clone()).on_progress(1, move |set| {
+        observed_copy.lock().unwrap().push(set.clone());
+    });
+
+    let result = ReachabilityComputation::<ForwardBfsStep>::run(config, mk_state(&graph, S011))?;
+
+    // BFS discovers {001, 010, 111} in the first step and {000, 110} in the second step.
+    let observed = observed.lock().unwrap();
+    assert_eq!(
+        *observed,
+        vec![
+            mk_states(&graph, &[S011, S001, S010, S111]),
+            mk_states(&graph, &[S011, S001, S010, S111, S000, S110]),
+        ]
+    );
+    assert_eq!(observed.last(), Some(&result));
+    Ok(())
+}
+
+#[test]
+fn test_single_variable_successors() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = ReachabilityConfig::from(&graph);
+    let vars = graph.variables().collect::<Vec<_>>();
+    let s011 = mk_state(&graph, S011);
+
+    // 011 -> 111 (x0), 011 -> 001 (x1), 011 -> 010 (x2).
+    let step = |config: &ReachabilityConfig, var: VariableId| {
+        SingleVariableSuccessors::step(&config.clone().with_step_variable(var), &s011)
+    };
+    assert_eq!(step(&config, vars[0])?, mk_state(&graph, S111));
+    assert_eq!(step(&config, vars[1])?, mk_state(&graph, S001));
+    assert_eq!(step(&config, vars[2])?, mk_state(&graph, S010));
+
+    // Without a variable, or with an inactive variable, there are no successors.
+    assert!(SingleVariableSuccessors::step(&config, &s011)?.is_empty());
+    let config = config.with_active_variables([vars[0]]);
+    assert!(step(&config, vars[1])?.is_empty());
+
+    // Only updating x0, 011 can reach 111, but nothing else.
+    let config = config.with_step_variable(vars[0]);
+    let result =
+        ReachabilityComputation::<IterativeUnion<SingleVariableSuccessors>>::run(config, s011)?;
+    assert_eq!(result, mk_states(&graph, &[S011, S111]));
+    Ok(())
+}
+
+#[test]
+fn test_multi_source_forward() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    // Seeds reaching each other in both orders (011 -> 001 -> 000 and 100 -> 000).
+    let seeds = vec![
+        mk_state(&graph, S001),
+        mk_states(&graph, &[S011, S100]),
+        mk_state(&graph, S000),
+        mk_states(&graph, ATTRACTOR_2),
+        graph.mk_empty_colored_vertices(),
+    ];
+
+    let results = multi_source_forward(&graph, &seeds)?;
+    assert_eq!(results.len(), seeds.len());
+    for (seed, result) in seeds.iter().zip(results.iter()) {
+        let expected = ReachabilityComputation::<IterativeUnion<SaturationSuccessors>>::run(
+            &graph,
+            seed.clone(),
+        )?;
+        assert_eq!(result, &expected);
+    }
+
+    // With a full config, only the active variables are used (here, x1 and x2).
+    let config =
+        ReachabilityConfig::new(graph.clone()).with_active_variables(graph.variables().skip(1));
+    let results = multi_source_forward(config.clone(), &seeds)?;
+    for (seed, result) in seeds.iter().zip(results.iter()) {
+        assert_eq!(
+            result,
+            &ReachabilityComputation::<IterativeUnion<SaturationSuccessors>>::run(
+                config.clone(),
+                seed.clone()
+            )?
+        );
+    }
+
+    // Seeds that are only covered in some colors.
+    let graph = create_parameterized_test_network();
+    let seeds = vec![
+        mk_state(&graph, 0b10),
+        mk_state(&graph, 0b00),
+        mk_states(&graph, &[0b01, 0b00]),
+    ];
+    let results = multi_source_forward(&graph, &seeds)?;
+    for (seed, result) in seeds.iter().zip(results.iter()) {
+        assert_eq!(
+            result,
+            &ReachabilityComputation::<IterativeUnion<SaturationSuccessors>>::run(
+                &graph,
+                seed.clone()
+            )?
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_reach_forward_state_accessors() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let initial = mk_state(&graph, S011);
+    let mut computation =
+        ReachabilityComputation::<IterativeUnion<SaturationSuccessors>>::configure(&graph, initial);
+    assert_eq!(
+        computation.state().current_cardinality(),
+        BigUint::from(1u32)
+    );
+    assert!(computation.state().current_symbolic_size() > 0);
+
+    let result = computation.compute()?;
+    assert_eq!(
+        computation.state().current_cardinality(),
+        result.exact_cardinality()
+    );
+    assert_eq!(
+        computation.state().current_symbolic_size(),
+        result.symbolic_size()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_reach_forward_with_label() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = ReachabilityConfig::from(&graph).label("model_42");
+    assert_eq!(
+        format!("{}done", crate::LogLabel(&config.label)),
+        "[model_42] done"
+    );
+    assert_eq!(format!("{}done", crate::LogLabel(&None)), "done");
+
+    let initial = mk_state(&graph, S100);
+    let result =
+        ReachabilityComputation::<IterativeUnion<SaturationSuccessors>>::run(config, initial)?;
+    assert_eq!(result, mk_states(&graph, &[S100, S000, S110, S111]));
+    Ok(())
+}
+
+#[test]
+fn test_can_reach_and_cannot_reach() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    let attractor_1 = mk_states(&graph, ATTRACTOR_1);
+    assert_eq!(
+        can_reach(&graph, &attractor_1)?,
+        mk_states(&graph, CAN_REACH_ATTR1)
+    );
+    assert_eq!(
+        cannot_reach(&graph, &attractor_1)?,
+        mk_states(&graph, &[S101, S110, S111])
+    );
+
+    let attractor_2 = mk_states(&graph, ATTRACTOR_2);
+    assert_eq!(
+        can_reach(&graph, &attractor_2)?,
+        mk_states(&graph, CAN_REACH_ATTR2)
+    );
+    assert_eq!(
+        cannot_reach(&graph, &attractor_2)?,
+        mk_states(&graph, &[S000, S001, S010])
+    );
+
+    let empty = graph.mk_empty_colored_vertices();
+    assert!(can_reach(&graph, &empty)?.is_empty());
+    assert_eq!(cannot_reach(&graph, &empty)?, mk_states(&graph, ALL_STATES));
+    Ok(())
+}
+
+#[test]
+fn test_reach_dyn_matches_static() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    for state in ALL_STATES {
+        let initial = mk_state(&graph, *state);
+
+        let saturation = ReachabilityConfig::from(&graph);
+        let bfs = ReachabilityConfig::from(&graph).strategy(ReachabilityStrategy::Bfs);
+
+        assert_eq!(
+            ForwardReachabilityDyn::run(saturation.clone(), initial.clone())?,
+            ForwardReachability::run(&graph, initial.clone())?
+        );
+        assert_eq!(
+            ForwardReachabilityDyn::run(bfs.clone(), initial.clone())?,
+            ForwardReachabilityBfs::run(&graph, initial.clone())?
+        );
+        assert_eq!(
+            BackwardReachabilityDyn::run(saturation, initial.clone())?,
+            BackwardReachability::run(&graph, initial.clone())?
+        );
+        assert_eq!(
+            BackwardReachabilityDyn::run(bfs, initial.clone())?,
+            BackwardReachabilityBfs::run(&graph, initial)?
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_reach_with_no_active_variables() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let initial = mk_states(&graph, &[S011, S100]);
+    for strategy in [ReachabilityStrategy::Saturation, ReachabilityStrategy::Bfs] {
+        let config = ReachabilityConfig::from(&graph)
+            .strategy(strategy)
+            .with_active_variables([]);
+        assert!(config.active_variables.is_empty());
+        assert_eq!(
+            ForwardReachabilityDyn::run(config.clone(), initial.clone())?,
+            initial
+        );
+        assert_eq!(
+            BackwardReachabilityDyn::run(config, initial.clone())?,
+            initial
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_reach_with_single_active_variable() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let x: Vec<VariableId> = graph.variables().collect();
+    for strategy in [ReachabilityStrategy::Saturation, ReachabilityStrategy::Bfs] {
+        let config = |var: VariableId| {
+            ReachabilityConfig::from(&graph)
+                .strategy(strategy)
+                .with_active_variables([var])
+        };
+
+        // Only x0 can update: 011 -> 111, but not 011 -> 001 or 011 -> 010.
+        let result = ForwardReachabilityDyn::run(config(x[0]), mk_state(&graph, S011))?;
+        assert_eq!(result, mk_states(&graph, &[S011, S111]));
+
+        // Only x2 can update: 011 -> 010, but 010 -> 000 requires x1.
+        let result = ForwardReachabilityDyn::run(config(x[2]), mk_state(&graph, S011))?;
+        assert_eq!(result, mk_states(&graph, &[S011, S010]));
+
+        // Only x1 can update: 100 -> 110, but 110 -> 111 requires x2.
+        let result = ForwardReachabilityDyn::run(config(x[1]), mk_state(&graph, S100))?;
+        assert_eq!(result, mk_states(&graph, &[S100, S110]));
+
+        // Backward with only x1: 010 -> 000 is kept, 001 -> 000 and 100 -> 000 are not.
+        let result = BackwardReachabilityDyn::run(config(x[1]), mk_state(&graph, S000))?;
+        assert_eq!(result, mk_states(&graph, &[S000, S010]));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_reach_forward_adaptive() -> Cancellable<()> {
+    init_logger();
+    // The example networks, plus a 6-cycle and two disjoint 2-cycles.
+    let cycle = [
+        (0b000, 0b100),
+        (0b100, 0b110),
+        (0b110, 0b111),
+        (0b111, 0b011),
+        (0b011, 0b001),
+        (0b001, 0b000),
+    ];
+    let two_cycles = [
+        (0b000, 0b100),
+        (0b100, 0b000),
+        (0b011, 0b111),
+        (0b111, 0b011),
+    ];
+    let mut graphs = vec![create_test_network(), create_parameterized_test_network()];
+    for transitions in [&cycle[..], &two_cycles[..]] {
+        let bn = from_transitions(3, transitions).expect("Failed to create network");
+        graphs.push(SymbolicAsyncGraph::new(&bn).expect("Failed to create graph"));
+    }
+
+    for graph in &graphs {
+        let num_vars = graph.variables().count();
+        for state in 0..(1u32 << num_vars) {
+            let initial = mk_state(graph, state);
+            let result = ForwardReachabilityAdaptive::run(graph, initial.clone())?;
+            assert_eq!(result, ForwardReachability::run(graph, initial)?);
+        }
+        let unit = graph.mk_unit_colored_vertices();
+        assert_eq!(ForwardReachabilityAdaptive::run(graph, unit.clone())?, unit);
+    }
+
+    // The hint belongs to the computation: the original config is not affected.
+    let graph = create_test_network();
+    let config = ReachabilityConfig::from(&graph);
+    let mut computation =
+        ForwardReachabilityAdaptive::configure(config.clone(), mk_state(&graph, S011));
+    computation.compute()?;
+    assert!(computation.context().saturation_hint.get().is_some());
+    assert!(config.saturation_hint.get().is_none());
+    Ok(())
+}
+
+#[test]
+fn test_pre_and_post_for_colors() -> Cancellable<()> {
+    init_logger();
+    let graph = create_parameterized_test_network();
+
+    // State 00 only has a successor (10) when p=false.
+    let s00 = mk_state(&graph, 0b00);
+    let p_false = graph.post(&s00).colors();
+    let p_true = graph.mk_unit_colors().minus(&p_false);
+    assert_eq!(p_false.exact_cardinality(), 1u32.into());
+    assert_eq!(p_true.exact_cardinality(), 1u32.into());
+
+    let s10 = mk_state(&graph, 0b10);
+    let s11 = mk_state(&graph, 0b11);
+    assert_eq!(
+        post_for_colors(&graph, &s00, &p_false),
+        s10.intersect_colors(&p_false)
+    );
+    assert!(post_for_colors(&graph, &s00, &p_true).is_empty());
+    assert_eq!(
+        pre_for_colors(&graph, &s10, &p_false),
+        s00.intersect_colors(&p_false)
+    );
+    assert_eq!(
+        pre_for_colors(&graph, &s10, &p_true),
+        s11.intersect_colors(&p_true)
+    );
+
+    // Reachability from a color-restricted set stays within the same colors.
+    let reachable = ForwardReachability::run(&graph, s00.intersect_colors(&p_false))?;
+    assert_eq!(
+        reachable,
+        mk_states(&graph, &[0b00, 0b10]).intersect_colors(&p_false)
+    );
+    let layer = post_for_colors(&graph, &reachable, &p_false);
+    assert!(layer.is_subset(&reachable));
+    Ok(())
+}
+
+#[test]
+fn test_reach_forward_until() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    // 110 is reachable from 011 (through 111).
+    let initial = mk_state(&graph, S011);
+    let target = mk_state(&graph, S110);
+    let (set, reached) =
+        ForwardReachabilityUntil::new(&graph, initial.clone(), target.clone()).compute()?;
+    assert_eq!(reached, graph.mk_unit_colors());
+    assert!(initial.is_subset(&set));
+    assert!(!set.intersect(&target).is_empty());
+    assert!(set.is_subset(&ForwardReachability::run(&graph, initial)?));
+
+    // 110 is not reachable from 001, so the full reachable set is computed.
+    let initial = mk_state(&graph, S001);
+    let (set, reached) =
+        ForwardReachabilityUntil::new(&graph, initial.clone(), target.clone()).compute()?;
+    assert!(reached.is_empty());
+    assert_eq!(set, mk_states(&graph, &[S000, S001]));
+
+    // If the initial set intersects the target, nothing is computed.
+    let mut computation = ForwardReachabilityUntil::new(&graph, target.clone(), target.clone());
+    assert_eq!(computation.compute()?, (target, graph.mk_unit_colors()));
+    assert_eq!(computation.state().iteration, 0);
+    Ok(())
+}
+
+#[test]
+fn test_reach_forward_until_per_color() -> Cancellable<()> {
+    init_logger();
+    let graph = create_parameterized_test_network();
+    // State 00 only has a successor when p=false.
+    let p_false = graph.post(&mk_state(&graph, 0b00)).colors();
+    let p_true = graph.mk_unit_colors().minus(&p_false);
+
+    // 10 is reachable from 01 only for p=true (01 -> 11 -> 10). For p=false, the search
+    // continues until the full reachable set {01, 11} is found.
+    let initial = mk_state(&graph, 0b01);
+    let target = mk_state(&graph, 0b10);
+    let (set, reached) =
+        ForwardReachabilityUntil::new(&graph, initial.clone(), target).compute()?;
+    assert_eq!(reached, p_true);
+    assert_eq!(
+        set.intersect_colors(&p_false),
+        mk_states(&graph, &[0b01, 0b11]).intersect_colors(&p_false)
+    );
+    assert!(
+        set.intersect_colors(&p_true)
+            .is_subset(&ForwardReachability::run(&graph, initial)?)
+    );
+    Ok(())
+}
+
+#[test]
+fn test_forward_reachability_avoiding() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let initial = mk_state(&graph, S011);
+
+    // 011 -> {001, 010, 111}; avoiding 111 also avoids the whole second attractor.
+    let avoid = mk_state(&graph, S111);
+    let result = ForwardReachabilityAvoiding::new(&graph, initial.clone(), &avoid).compute()?;
+    assert_eq!(result, mk_states(&graph, &[S000, S001, S010, S011]));
+
+    // Avoiding nothing is the same as normal reachability.
+    let empty = graph.mk_empty_colored_vertices();
+    let result = ForwardReachabilityAvoiding::new(&graph, initial.clone(), &empty).compute()?;
+    assert_eq!(result, ForwardReachability::run(&graph, initial.clone())?);
+
+    // The avoided set composes with the universe of the config.
+    let config = ReachabilityConfig::new(graph.clone())
+        .with_universe(&mk_states(&graph, &[S011, S010, S000, S111, S110]));
+    let result = ForwardReachabilityAvoiding::new(config, initial.clone(), &avoid).compute()?;
+    assert_eq!(result, mk_states(&graph, &[S000, S010, S011]));
+
+    // If the initial state is avoided, nothing is reachable.
+    let result = ForwardReachabilityAvoiding::new(&graph, initial.clone(), &initial).compute()?;
+    assert!(result.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_reach_peak_symbolic_size() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let initial = mk_state(&graph, S011);
+
+    let config = ReachabilityConfig::new(graph.clone());
+    ForwardReachability::run(config.clone(), initial.clone())?;
+    assert_eq!(config.peak_symbolic_size(), 0);
+
+    // The tracker is shared with the clone used by the computation.
+    let config = ReachabilityConfig::new(graph.clone()).track_peak_symbolic_size();
+    let result = ForwardReachability::run(config.clone(), initial)?;
+    assert!(config.peak_symbolic_size() >= result.symbolic_size());
+    Ok(())
+}
+
+#[test]
+fn test_reach_run_with_config() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    // Only the first variable (the most significant bit) can be updated.
+    let var = graph.variables().next().unwrap();
+    let config = ReachabilityConfig::new(graph.clone()).with_active_variables([var]);
+    let expected = mk_states(&graph, &[S011, S111]);
+
+    let initial = mk_state(&graph, S011);
+    assert_eq!(
+        ForwardReachability::run(config.clone(), initial.clone())?,
+        expected
+    );
+    assert_eq!(
+        ForwardReachabilityBfs::run(config.clone(), initial)?,
+        expected
+    );
+
+    let initial = mk_state(&graph, S111);
+    assert_eq!(
+        BackwardReachability::run(config.clone(), initial.clone())?,
+        expected
+    );
+    assert_eq!(BackwardReachabilityBfs::run(config, initial)?, expected);
+    Ok(())
+}
+
+#[test]
+fn test_post_and_pre_within() {
+    init_logger();
+    let graph = create_test_network();
+    let set = mk_state(&graph, S011);
+    let universe = mk_states(&graph, &[S001, S011, S111]);
+    // 011 -> {001, 010, 111}, but 010 is outside the universe.
+    assert_eq!(
+        post_within(&graph, &set, &universe),
+        mk_states(&graph, &[S001, S111])
+    );
+    assert_eq!(
+        post_within(&graph, &set, &graph.mk_unit_colored_vertices()),
+        graph.post(&set)
+    );
+
+    // 000 <- {001, 010, 100}, but only 001 is in the universe.
+    let set = mk_state(&graph, S000);
+    assert_eq!(pre_within(&graph, &set, &universe), mk_state(&graph, S001));
+    assert!(pre_within(&graph, &set, &graph.mk_empty_colored_vertices()).is_empty());
+}
+
+#[test]
+fn test_forward_with_changed_variables() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let vars = graph.variables().collect::<Vec<_>>();
+
+    // From 100, all three variables change: 100 -> 000, 100 -> 110 -> 111.
+    let (reachable, changed) = forward_with_changed_variables(&graph, mk_state(&graph, S100))?;
+    assert_eq!(reachable, mk_states(&graph, &[S000, S100, S110, S111]));
+    assert_eq!(changed, BTreeSet::from_iter(vars.clone()));
+
+    // From 001, only the last variable changes.
+    let (reachable, changed) = forward_with_changed_variables(&graph, mk_state(&graph, S001))?;
+    assert_eq!(reachable, mk_states(&graph, &[S000, S001]));
+    assert_eq!(changed, BTreeSet::from([vars[2]]));
+
+    // A fixed point has no changing variables.
+    let (_, changed) = forward_with_changed_variables(&graph, mk_state(&graph, S000))?;
+    assert!(changed.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_max_symbolic_size_keeps_partial_result() {
+    init_logger();
+    let graph = create_test_network();
+    let initial = mk_state(&graph, S011);
+    let full = ForwardReachability::run(&graph, initial.clone()).unwrap();
+
+    // Every set has a positive symbolic size, so the first increase exceeds the limit.
+    let config = ReachabilityConfig::new(graph.clone()).max_symbolic_size(0);
+    let mut computation = ForwardReachability::configure(config, initial.clone());
+    let error = computation.compute().unwrap_err();
+    assert_eq!(error.cause(), "ReachabilityConfig::max_symbolic_size");
+
+    let partial = computation.state().set.clone();
+    assert!(initial.is_subset(&partial));
+    assert!(partial.is_subset(&full));
+    assert_ne!(partial, initial);
+}
+
+#[test]
+fn test_reachable_set() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    let forward = ReachableSet::forward(&graph, mk_state(&graph, S100))?;
+    assert_eq!(forward.cardinality(), BigUint::from(4u32));
+    assert!(forward.contains(&mk_state(&graph, S000)));
+    assert!(forward.contains(&mk_state(&graph, S111)));
+    assert!(!forward.contains(&mk_state(&graph, S011)));
+    assert!(forward.contains(&mk_states(&graph, &[S011, S110])));
+    assert!(!forward.contains(&graph.mk_empty_colored_vertices()));
+
+    let backward = ReachableSet::backward(&graph, mk_state(&graph, S000))?;
+    assert_eq!(backward.into_set(), mk_states(&graph, CAN_REACH_ATTR1));
+    Ok(())
+}
+
+#[test]
+fn test_verify_against_naive() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    for state in ALL_STATES {
+        let initial = mk_state(&graph, *state);
+        assert!(verify_against_naive(&graph, initial)?);
+    }
+    let naive = reach_forward_naive(&graph, mk_state(&graph, S100))?;
+    assert_eq!(naive, mk_states(&graph, &[S000, S100, S110, S111]));
+
+    // Restricted variables: only the first variable can change.
+    let config =
+        ReachabilityConfig::new(graph.clone()).with_active_variables(graph.variables().take(1));
+    let naive = reach_forward_naive(config.clone(), mk_state(&graph, S100))?;
+    assert_eq!(naive, mk_states(&graph, &[S000, S100]));
+    assert!(verify_against_naive(config, mk_state(&graph, S100))?);
+
+    // Parametrized network with two colors.
+    let graph = create_parameterized_test_network();
+    for state in 0..4 {
+        assert!(verify_against_naive(&graph, mk_state(&graph, state))?);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_forward_reachability_guarded() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let vars = graph.variables().collect::<Vec<_>>();
+
+    // Without a guard, the result is the same as for the unguarded procedure.
+    for state in ALL_STATES {
+        let initial = mk_state(&graph, *state);
+        assert_eq!(
+            ForwardReachabilityGuarded::run(&graph, initial.clone())?,
+            ForwardReachability::run(&graph, initial)?
+        );
+    }
+
+    // The first variable cannot turn off: 100 -> 000 is forbidden.
+    let x0 = vars[0];
+    let config = ReachabilityConfig::new(graph.clone())
+        .with_transition_guard(move |var, value| !(var == x0 && value));
+    let reachable = ForwardReachabilityGuarded::run(config, mk_state(&graph, S100))?;
+    assert_eq!(reachable, mk_states(&graph, &[S100, S110, S111]));
+
+    // The second variable cannot turn off: 011 -> 001 and 010 -> 000 are forbidden.
+    let x1 = vars[1];
+    let config = ReachabilityConfig::new(graph.clone())
+        .with_transition_guard(move |var, value| !(var == x1 && value));
+    let reachable = ForwardReachabilityGuarded::run(config, mk_state(&graph, S011))?;
+    assert_eq!(reachable, mk_states(&graph, &[S010, S011, S110, S111]));
+    Ok(())
 }
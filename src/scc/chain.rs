@@ -1,12 +1,13 @@
-use crate::log_set;
 use crate::reachability::{BackwardReachability, ForwardReachability};
-use crate::scc::{SccConfig, filter_scc};
+use crate::scc::{SccConfig, filter_scc, long_lived_remainder_colors};
 use crate::trimming::TrimComputation;
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
 use computation_process::Incomplete::Suspended;
 use computation_process::{Completable, Computable, GeneratorStep, Stateful};
 use log::{debug, info};
+use num_bigint::BigUint;
 
 /// Internal state for the chain-based SCC algorithm.
 ///
@@ -15,6 +16,47 @@ use log::{debug, info};
 pub struct ChainState {
     computing: Step,
     to_process: Vec<Step0>,
+    prepared: bool,
+}
+
+impl ChainState {
+    /// The number of vertices that still need to be processed: the universe of the current
+    /// iteration (if any) together with all pending universes.
+    ///
+    /// The value is not a reliable estimate of the remaining work (some universes can be
+    /// resolved in a single iteration), but it never increases and can be used as a progress
+    /// hint while polling the computation.
+    pub fn remaining_cardinality(&self) -> BigUint {
+        self.remaining_sets().map(|it| it.exact_cardinality()).sum()
+    }
+
+    /// The vertices that still need to be processed (see [`ChainState::remaining_cardinality`]),
+    /// or `None` if the decomposition is finished.
+    ///
+    /// The set never grows (for each color) as the decomposition proceeds.
+    pub fn remaining_universe(&self) -> Option<GraphColoredVertices> {
+        self.remaining_sets().cloned().reduce(|a, b| a.union(&b))
+    }
+
+    /// The total symbolic size (BDD nodes) of the sets that still need to be processed
+    /// (see [`ChainState::remaining_cardinality`]).
+    pub fn remaining_symbolic_size(&self) -> usize {
+        self.remaining_sets().map(|it| it.symbolic_size()).sum()
+    }
+
+    /// The universe of the current iteration (if any) together with all pending universes.
+    fn remaining_sets(&self) -> impl Iterator<Item = &GraphColoredVertices> {
+        let current = match &self.computing {
+            Step::Idle => None,
+            Step::Trimming(step) => Some(&step.full_universe),
+            Step::Basin(step) => Some(&step.universe),
+            Step::Scc(step) => Some(&step.universe),
+        };
+        self.to_process
+            .iter()
+            .map(|it| &it.full_universe)
+            .chain(current)
+    }
 }
 
 impl From<&SymbolicAsyncGraph> for ChainState {
@@ -37,6 +79,7 @@ impl From<GraphColoredVertices> for ChainState {
                 full_universe: value,
                 pivot_hint: None,
             }],
+            prepared: false,
         }
     }
 }
@@ -54,9 +97,33 @@ impl GeneratorStep<SccConfig, ChainState, GraphColoredVertices> for ChainStep {
     ) -> Completable<Option<GraphColoredVertices>> {
         match &mut state.computing {
             Step::Idle => {
+                let initial = !state.prepared;
+                if !state.prepared {
+                    for todo in state.to_process.iter_mut() {
+                        todo.full_universe = context.prepare_universe(&todo.full_universe)?;
+                        if todo.pivot_hint.is_none() {
+                            todo.pivot_hint = context.initial_pivot_hint.clone();
+                        }
+                    }
+                    state.to_process.retain(|it| !it.full_universe.is_empty());
+                    state.prepared = true;
+                }
+
                 // We are in-between iterations. We need to pick a new set for processing.
                 // Pick a new state for processing.
 
+                if state.to_process.len() > 1
+                    && let Some(colors) = long_lived_remainder_colors(
+                        context,
+                        state.to_process.iter().map(|it| &it.full_universe),
+                    )
+                {
+                    state.to_process.retain_mut(|it| {
+                        it.full_universe = it.full_universe.intersect_colors(&colors);
+                        !it.full_universe.is_empty()
+                    });
+                }
+
                 let Some(mut todo) = state.to_process.pop() else {
                     // If there is nothing to process, we are done.
                     return Ok(None);
@@ -65,14 +132,18 @@ impl GeneratorStep<SccConfig, ChainState, GraphColoredVertices> for ChainStep {
                 let Some(full_universe) = context.apply_long_lived_filter(&todo.full_universe)
                 else {
                     // The set is not long-lived, we can ignore it.
-                    debug!("Candidate set empty after long-lived filtering.");
+                    debug!(
+                        "{}Candidate set empty after long-lived filtering.",
+                        LogLabel(&context.label)
+                    );
                     return Err(Suspended);
                 };
 
                 todo.full_universe = full_universe;
 
                 info!(
-                    "Start processing ({}); {} sets remaining (BDD nodes={})",
+                    "{}Start processing ({}); {} sets remaining (BDD nodes={})",
+                    LogLabel(&context.label),
                     log_set(&todo.full_universe),
                     state.to_process.len(),
                     state
@@ -82,7 +153,7 @@ impl GeneratorStep<SccConfig, ChainState, GraphColoredVertices> for ChainStep {
                         .sum::<usize>()
                 );
 
-                state.computing = Step::Trimming(todo.advance(context));
+                state.computing = Step::Trimming(todo.advance(context, initial));
                 Err(Suspended)
             }
             Step::Trimming(step) => {
@@ -105,7 +176,11 @@ impl GeneratorStep<SccConfig, ChainState, GraphColoredVertices> for ChainStep {
                 let basin = result.basin;
                 let universe = result.universe;
 
-                debug!("Extracted raw SCC ({})", log_set(&raw_scc));
+                debug!(
+                    "{}Extracted raw SCC ({})",
+                    LogLabel(&context.label),
+                    log_set(&raw_scc)
+                );
 
                 // Enqueue the remaining states for further processing.
                 let remaining_basin = basin.minus(&raw_scc);
@@ -113,21 +188,12 @@ impl GeneratorStep<SccConfig, ChainState, GraphColoredVertices> for ChainStep {
 
                 if !remaining_basin.is_empty() {
                     // Try to find *some* states that are direct predecessors of SCC inside
-                    // the remaining basin.
-                    let mut hint = context.graph.mk_empty_colored_vertices();
-                    for var in context.graph.variables().rev() {
-                        let var_pre = context
-                            .graph
-                            .var_pre_out(var, &raw_scc)
-                            .intersect(&remaining_basin);
-                        if !var_pre.is_empty() {
-                            hint = var_pre;
-                            break;
-                        }
-                    }
+                    // the remaining basin (successors if the graph is reversed).
+                    let hint = scc_border(context, &raw_scc, &remaining_basin, context.reversed);
 
                     debug!(
-                        "Pushed remaining BASIN ({}) with hint ({}).",
+                        "{}Pushed remaining BASIN ({}) with hint ({}).",
+                        LogLabel(&context.label),
                         log_set(&remaining_basin),
                         log_set(&hint),
                     );
@@ -140,21 +206,12 @@ impl GeneratorStep<SccConfig, ChainState, GraphColoredVertices> for ChainStep {
 
                 if !remaining_rest.is_empty() {
                     // Try to find *some* states that are direct successors of SCC inside
-                    // the remaining set.
-                    let mut hint = context.graph.mk_empty_colored_vertices();
-                    for var in context.graph.variables().rev() {
-                        let var_post = context
-                            .graph
-                            .var_post_out(var, &raw_scc)
-                            .intersect(&remaining_rest);
-                        if !var_post.is_empty() {
-                            hint = var_post;
-                            break;
-                        }
-                    }
+                    // the remaining set (predecessors if the graph is reversed).
+                    let hint = scc_border(context, &raw_scc, &remaining_rest, !context.reversed);
 
                     debug!(
-                        "Pushed remaining REST ({}) with hint ({}).",
+                        "{}Pushed remaining REST ({}) with hint ({}).",
+                        LogLabel(&context.label),
                         log_set(&remaining_rest),
                         log_set(&hint),
                     );
@@ -167,7 +224,7 @@ impl GeneratorStep<SccConfig, ChainState, GraphColoredVertices> for ChainStep {
 
                 // Remove colors where the SCC is a singleton state:
                 state.computing = Step::Idle;
-                if let Some(scc) = filter_scc(context, raw_scc) {
+                if let Some(scc) = filter_scc(context, raw_scc, &universe) {
                     Ok(Some(scc))
                 } else {
                     Err(Suspended)
@@ -185,6 +242,67 @@ enum Step {
     Scc(Box<Step3>),
 }
 
+/// A reachability computation in the direction selected by [`SccConfig::reversed`].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Reachability {
+    Forward(ForwardReachability),
+    Backward(BackwardReachability),
+}
+
+impl Reachability {
+    /// The reachability used to compute the basin of a pivot (backward, unless reversed).
+    fn basin(context: &SccConfig, graph: SymbolicAsyncGraph, pivot: GraphColoredVertices) -> Self {
+        let config = context.reachability_config(graph);
+        if context.reversed {
+            Reachability::Forward(ForwardReachability::configure(config, pivot))
+        } else {
+            Reachability::Backward(BackwardReachability::configure(config, pivot))
+        }
+    }
+
+    /// The reachability used to compute the SCC of a pivot within its basin (forward,
+    /// unless reversed).
+    fn scc(context: &SccConfig, graph: SymbolicAsyncGraph, pivot: GraphColoredVertices) -> Self {
+        let config = context.reachability_config(graph);
+        if context.reversed {
+            Reachability::Backward(BackwardReachability::configure(config, pivot))
+        } else {
+            Reachability::Forward(ForwardReachability::configure(config, pivot))
+        }
+    }
+}
+
+impl Computable<GraphColoredVertices> for Reachability {
+    fn try_compute(&mut self) -> Completable<GraphColoredVertices> {
+        match self {
+            Reachability::Forward(x) => x.try_compute(),
+            Reachability::Backward(x) => x.try_compute(),
+        }
+    }
+}
+
+/// Find *some* states of `target` that are direct successors (or predecessors if
+/// `successors` is false) of the `scc`.
+fn scc_border(
+    context: &SccConfig,
+    scc: &GraphColoredVertices,
+    target: &GraphColoredVertices,
+    successors: bool,
+) -> GraphColoredVertices {
+    for var in context.active_variables.iter().rev() {
+        let border = if successors {
+            context.graph.var_post_out(*var, scc)
+        } else {
+            context.graph.var_pre_out(*var, scc)
+        };
+        let border = border.intersect(target);
+        if !border.is_empty() {
+            return border;
+        }
+    }
+    context.graph.mk_empty_colored_vertices()
+}
+
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Step0 {
     full_universe: GraphColoredVertices,
@@ -202,14 +320,14 @@ struct Step1 {
 struct Step2 {
     universe: GraphColoredVertices,
     pivot: GraphColoredVertices,
-    basin: BackwardReachability,
+    basin: Reachability,
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Step3 {
     universe: GraphColoredVertices,
     basin: GraphColoredVertices,
-    scc: ForwardReachability,
+    scc: Reachability,
 }
 
 struct IterationResult {
@@ -219,11 +337,12 @@ struct IterationResult {
 }
 
 impl Step0 {
-    pub fn advance(&mut self, context: &SccConfig) -> Box<Step1> {
+    pub fn advance(&mut self, context: &SccConfig, initial: bool) -> Box<Step1> {
         let mut result = Step1 {
-            universe: context
-                .should_trim
-                .build_computation(&context.graph, self.full_universe.clone()),
+            universe: context.trim_setting(initial).build_computation(
+                context.reachability_config(context.graph.clone()),
+                self.full_universe.clone(),
+            ),
             full_universe: context.graph.mk_empty_colored_vertices(),
             pivot_hint: self
                 .pivot_hint
@@ -242,16 +361,26 @@ impl Step1 {
         let universe = self.universe.try_compute()?;
 
         if universe.is_empty() {
-            debug!("Candidate set empty after trimming.");
+            debug!(
+                "{}Candidate set empty after trimming.",
+                LogLabel(&context.label)
+            );
             return Ok(None);
         }
 
         let Some(universe) = context.apply_long_lived_filter(&universe) else {
-            debug!("Candidate set empty after trimming and long-term filtering.");
+            debug!(
+                "{}Candidate set empty after trimming and long-term filtering.",
+                LogLabel(&context.label)
+            );
             return Ok(None);
         };
 
-        debug!("Candidate set trimmed ({}).", log_set(&universe));
+        debug!(
+            "{}Candidate set trimmed ({}).",
+            LogLabel(&context.label),
+            log_set(&universe)
+        );
 
         let mut pivot_hint = universe.intersect(&self.pivot_hint);
         if pivot_hint.is_empty() {
@@ -259,12 +388,18 @@ impl Step1 {
             // additional hint states at the border of the trimmed set.
 
             let removed = self.full_universe.minus(&universe);
-            for var in context.graph.variables().rev() {
-                let var_post = context.graph.var_post(var, &removed).intersect(&universe);
-                if !var_post.is_empty() {
-                    pivot_hint = var_post;
+            for var in context.active_variables.iter().rev() {
+                let border = if context.reversed {
+                    context.graph.var_pre(*var, &removed)
+                } else {
+                    context.graph.var_post(*var, &removed)
+                };
+                let border = border.intersect(&universe);
+                if !border.is_empty() {
+                    pivot_hint = border;
                     debug!(
-                        "Updated pivot hint after trimming ({}).",
+                        "{}Updated pivot hint after trimming ({}).",
+                        LogLabel(&context.label),
                         log_set(&pivot_hint)
                     );
                     break;
@@ -273,15 +408,15 @@ impl Step1 {
         }
 
         let pivot = if pivot_hint.is_empty() {
-            universe.pick_vertex()
+            context.pick_pivot(&universe)
         } else {
-            pivot_hint.pick_vertex()
+            context.pick_pivot(&pivot_hint)
         };
 
         let graph = context.graph.restrict(&universe);
 
         let result = Step2 {
-            basin: BackwardReachability::configure(&graph, pivot.clone()),
+            basin: Reachability::basin(context, graph, pivot.clone()),
             universe,
             pivot,
         };
@@ -295,7 +430,7 @@ impl Step2 {
         let basin = self.basin.try_compute()?;
         let basin_graph = context.graph.restrict(&basin);
         let mut result = Step3 {
-            scc: ForwardReachability::configure(&basin_graph, self.pivot.clone()),
+            scc: Reachability::scc(context, basin_graph.clone(), self.pivot.clone()),
             universe: basin_graph.mk_empty_colored_vertices(),
             basin,
         };
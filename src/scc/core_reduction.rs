@@ -0,0 +1,81 @@
+use crate::reachability::ReachabilityConfig;
+use crate::scc::retain_long_lived;
+use crate::trimming::{TrimComputation, TrimSetting};
+use crate::{LogLabel, log_set};
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use computation_process::{Completable, Computable};
+use log::debug;
+
+/// A computation that reduces a set to its "interesting core" before SCC detection: it first
+/// applies trimming (see [`TrimSetting`]) and then (optionally) retains only the colors in which
+/// the trimmed set is long-lived (see [`SccConfig::filter_long_lived`]).
+///
+/// The result is the greatest subset of the initial set that is trimmed and long-lived.
+/// Note that long-lived filtering only removes whole colors, hence it never exposes new
+/// sinks or sources and the two steps do not need to be repeated.
+///
+/// Use [`CoreReduction::new`] together with the builder methods, or
+/// [`SccConfig::core_reduction`] to inherit the settings of an SCC configuration.
+///
+/// [`SccConfig::filter_long_lived`]: crate::scc::SccConfig::filter_long_lived
+/// [`SccConfig::core_reduction`]: crate::scc::SccConfig::core_reduction
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CoreReduction {
+    config: ReachabilityConfig,
+    set: GraphColoredVertices,
+    should_trim: TrimSetting,
+    filter_long_lived: bool,
+    trim: Option<TrimComputation>,
+}
+
+impl CoreReduction {
+    /// Create a new reduction of the given `set` with default settings (trim both sinks and
+    /// sources; no long-lived filtering).
+    ///
+    /// The `config` is typically just a
+    /// [`SymbolicAsyncGraph`](biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph),
+    /// but a full [`ReachabilityConfig`] can be used as well.
+    pub fn new(config: impl Into<ReachabilityConfig>, set: GraphColoredVertices) -> CoreReduction {
+        CoreReduction {
+            config: config.into(),
+            set,
+            should_trim: TrimSetting::default(),
+            filter_long_lived: false,
+            trim: None,
+        }
+    }
+
+    /// Update the trimming applied by this reduction.
+    pub fn should_trim(mut self, should_trim: TrimSetting) -> CoreReduction {
+        self.should_trim = should_trim;
+        self
+    }
+
+    /// Enable or disable long-lived filtering of the trimmed set.
+    pub fn filter_long_lived(mut self, filter_long_lived: bool) -> CoreReduction {
+        self.filter_long_lived = filter_long_lived;
+        self
+    }
+}
+
+impl Computable<GraphColoredVertices> for CoreReduction {
+    fn try_compute(&mut self) -> Completable<GraphColoredVertices> {
+        let trim = self.trim.get_or_insert_with(|| {
+            self.should_trim
+                .build_computation(self.config.clone(), self.set.clone())
+        });
+        let trimmed = trim.try_compute()?;
+
+        if !self.filter_long_lived {
+            return Ok(trimmed);
+        }
+
+        let result = retain_long_lived(&self.config.graph, &trimmed);
+        debug!(
+            "{}Core reduction finished with ({}).",
+            LogLabel(&self.config.label),
+            log_set(&result)
+        );
+        Ok(result)
+    }
+}
@@ -1,12 +1,13 @@
-use crate::log_set;
 use crate::reachability::ReachabilityAlgorithm;
-use crate::scc::{SccConfig, filter_scc};
+use crate::scc::{SccConfig, filter_scc, long_lived_remainder_colors};
 use crate::trimming::TrimComputation;
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
 use computation_process::Incomplete::Suspended;
 use computation_process::{Completable, Computable, GeneratorStep};
 use log::{debug, info};
+use num_bigint::BigUint;
 use std::marker::PhantomData;
 
 /// Internal state for the forward-backward SCC algorithm.
@@ -16,6 +17,81 @@ use std::marker::PhantomData;
 pub struct FwdBwdState<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm> {
     computing: Step<FWD, BWD>,
     to_process: Vec<GraphColoredVertices>,
+    prepared: bool,
+}
+
+impl<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm> FwdBwdState<FWD, BWD> {
+    /// The pivot of the iteration that is currently in progress (if any).
+    pub fn current_pivot(&self) -> Option<&GraphColoredVertices> {
+        match &self.computing {
+            Step::Backward(step) => Some(&step.pivot),
+            Step::Forward(step) => Some(&step.pivot),
+            Step::ReversedForward(step) => Some(&step.pivot),
+            Step::ReversedBackward(step) => Some(&step.pivot),
+            Step::Idle | Step::Trimming(_) => None,
+        }
+    }
+
+    /// The backward reachable set of the current pivot. This set is still growing
+    /// while the backward reachability is in progress, and if [`SccConfig::reversed`]
+    /// is set, it is only available once the forward reachability is finished.
+    pub fn current_backward(&self) -> Option<&GraphColoredVertices> {
+        match &self.computing {
+            Step::Backward(step) => Some(&step.first.state().set),
+            Step::Forward(step) => Some(&step.first),
+            Step::ReversedBackward(step) => Some(&step.second.state().set),
+            Step::Idle | Step::Trimming(_) | Step::ReversedForward(_) => None,
+        }
+    }
+
+    /// The forward reachable set of the current pivot. This set is still growing
+    /// while the forward reachability is in progress, and unless [`SccConfig::reversed`]
+    /// is set, it is only available once the backward reachability is finished.
+    pub fn current_forward(&self) -> Option<&GraphColoredVertices> {
+        match &self.computing {
+            Step::Forward(step) => Some(&step.second.state().set),
+            Step::ReversedForward(step) => Some(&step.first.state().set),
+            Step::ReversedBackward(step) => Some(&step.first),
+            Step::Idle | Step::Trimming(_) | Step::Backward(_) => None,
+        }
+    }
+
+    /// The number of vertices that still need to be processed: the universe of the current
+    /// iteration (if any) together with all pending universes.
+    ///
+    /// The value is not a reliable estimate of the remaining work (some universes can be
+    /// resolved in a single iteration), but it never increases and can be used as a progress
+    /// hint while polling the computation.
+    pub fn remaining_cardinality(&self) -> BigUint {
+        self.remaining_sets().map(|it| it.exact_cardinality()).sum()
+    }
+
+    /// The vertices that still need to be processed (see [`FwdBwdState::remaining_cardinality`]),
+    /// or `None` if the decomposition is finished.
+    ///
+    /// The set never grows (for each color) as the decomposition proceeds.
+    pub fn remaining_universe(&self) -> Option<GraphColoredVertices> {
+        self.remaining_sets().cloned().reduce(|a, b| a.union(&b))
+    }
+
+    /// The total symbolic size (BDD nodes) of the sets that still need to be processed
+    /// (see [`FwdBwdState::remaining_cardinality`]).
+    pub fn remaining_symbolic_size(&self) -> usize {
+        self.remaining_sets().map(|it| it.symbolic_size()).sum()
+    }
+
+    /// The universe of the current iteration (if any) together with all pending universes.
+    fn remaining_sets(&self) -> impl Iterator<Item = &GraphColoredVertices> {
+        let current = match &self.computing {
+            Step::Idle => None,
+            Step::Trimming(step) => Some(&step.full_universe),
+            Step::Backward(step) => Some(&step.universe),
+            Step::ReversedForward(step) => Some(&step.universe),
+            Step::Forward(step) => Some(&step.universe),
+            Step::ReversedBackward(step) => Some(&step.universe),
+        };
+        self.to_process.iter().chain(current)
+    }
 }
 
 /// Step implementation for the forward-backward SCC algorithm.
@@ -41,6 +117,7 @@ impl<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm> From<GraphColoredVe
         FwdBwdState {
             computing: Step::Idle,
             to_process: vec![value],
+            prepared: false,
         }
     }
 }
@@ -62,8 +139,27 @@ impl<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm>
     ) -> Completable<Option<GraphColoredVertices>> {
         match &mut state.computing {
             Step::Idle => {
+                let initial = !state.prepared;
+                if !state.prepared {
+                    for set in state.to_process.iter_mut() {
+                        *set = context.prepare_universe(set)?;
+                    }
+                    state.to_process.retain(|it| !it.is_empty());
+                    state.prepared = true;
+                }
+
                 // Pick a new state for processing.
 
+                if state.to_process.len() > 1
+                    && let Some(colors) =
+                        long_lived_remainder_colors(context, state.to_process.iter())
+                {
+                    state.to_process.retain_mut(|it| {
+                        *it = it.intersect_colors(&colors);
+                        !it.is_empty()
+                    });
+                }
+
                 let Some(todo) = state.to_process.pop() else {
                     // If there is nothing to process, we are done.
                     return Ok(None);
@@ -71,12 +167,16 @@ impl<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm>
 
                 let Some(todo) = context.apply_long_lived_filter(&todo) else {
                     // The set is not long-lived, we can ignore it.
-                    debug!("Candidate set empty after long-lived filtering.");
+                    debug!(
+                        "{}Candidate set empty after long-lived filtering.",
+                        LogLabel(&context.label)
+                    );
                     return Err(Suspended);
                 };
 
                 info!(
-                    "Start processing ({}); {} sets remaining (BDD nodes={})",
+                    "{}Start processing ({}); {} sets remaining (BDD nodes={})",
+                    LogLabel(&context.label),
                     log_set(&todo),
                     state.to_process.len(),
                     state
@@ -86,54 +186,65 @@ impl<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm>
                         .sum::<usize>()
                 );
 
-                state.computing = Step::Trimming(Box::new(Step1::new(context, todo)));
+                state.computing = Step::Trimming(Box::new(Step1::new(context, todo, initial)));
                 Err(Suspended)
             }
             Step::Trimming(step) => {
-                let Some(trimmed) = step.try_advance::<BWD>(context)? else {
-                    // If the set is empty after trimming/filtering, reset the state and stop.
-                    state.computing = Step::Idle;
-                    return Err(Suspended);
-                };
+                if context.reversed {
+                    let Some(trimmed) = step.try_advance::<FWD>(context)? else {
+                        // If the set is empty after trimming/filtering, reset the state and stop.
+                        state.computing = Step::Idle;
+                        return Err(Suspended);
+                    };
+
+                    state.computing = Step::ReversedForward(Box::new(trimmed));
+                } else {
+                    let Some(trimmed) = step.try_advance::<BWD>(context)? else {
+                        // If the set is empty after trimming/filtering, reset the state and stop.
+                        state.computing = Step::Idle;
+                        return Err(Suspended);
+                    };
 
-                state.computing = Step::Backward(Box::new(trimmed));
+                    state.computing = Step::Backward(Box::new(trimmed));
+                }
                 Err(Suspended)
             }
             Step::Backward(step) => {
                 state.computing = Step::Forward(Box::new(step.try_advance::<FWD>(context)?));
                 Err(Suspended)
             }
+            Step::ReversedForward(step) => {
+                state.computing =
+                    Step::ReversedBackward(Box::new(step.try_advance::<BWD>(context)?));
+                Err(Suspended)
+            }
             Step::Forward(step) => {
                 let result = step.try_advance(context)?;
-                let scc = result.scc;
-                let forward = result.forward;
-                let backward = result.backward;
-                let universe = result.universe;
-
-                // Enqueue the remaining states for further processing.
-                let remaining_backward = backward.minus(&forward);
-                let remaining_forward = forward.minus(&backward);
-                let remaining_rest = universe.minus(&backward).minus(&forward);
-
-                debug!(
-                    "Adding remaining FWD ({}), BWD ({}), and REST ({}) sets.",
-                    log_set(&remaining_forward),
-                    log_set(&remaining_backward),
-                    log_set(&remaining_rest),
+                enqueue_remaining(
+                    context,
+                    &mut state.to_process,
+                    &result.universe,
+                    &result.second,
+                    &result.first,
                 );
-
-                if !remaining_backward.is_empty() {
-                    state.to_process.push(remaining_backward);
-                }
-                if !remaining_forward.is_empty() {
-                    state.to_process.push(remaining_forward);
-                }
-                if !remaining_rest.is_empty() {
-                    state.to_process.push(remaining_rest);
+                state.computing = Step::Idle;
+                if let Some(scc) = result.scc {
+                    Ok(Some(scc))
+                } else {
+                    Err(Suspended)
                 }
-
+            }
+            Step::ReversedBackward(step) => {
+                let result = step.try_advance(context)?;
+                enqueue_remaining(
+                    context,
+                    &mut state.to_process,
+                    &result.universe,
+                    &result.first,
+                    &result.second,
+                );
                 state.computing = Step::Idle;
-                if let Some(scc) = scc {
+                if let Some(scc) = result.scc {
                     Ok(Some(scc))
                 } else {
                     Err(Suspended)
@@ -143,86 +254,146 @@ impl<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm>
     }
 }
 
+/// Enqueue the remaining states of an iteration with the given `forward` and `backward`
+/// sets for further processing.
+fn enqueue_remaining(
+    context: &SccConfig,
+    to_process: &mut Vec<GraphColoredVertices>,
+    universe: &GraphColoredVertices,
+    forward: &GraphColoredVertices,
+    backward: &GraphColoredVertices,
+) {
+    if forward == universe && backward == universe {
+        // Fast path: the whole universe is one SCC, so nothing remains.
+        debug!(
+            "{}The whole universe is a single SCC.",
+            LogLabel(&context.label)
+        );
+        return;
+    }
+
+    let remaining_backward = backward.minus(forward);
+    let remaining_forward = forward.minus(backward);
+    let remaining_rest = universe.minus(backward).minus(forward);
+
+    debug!(
+        "{}Adding remaining FWD ({}), BWD ({}), and REST ({}) sets.",
+        LogLabel(&context.label),
+        log_set(&remaining_forward),
+        log_set(&remaining_backward),
+        log_set(&remaining_rest),
+    );
+
+    if !remaining_backward.is_empty() {
+        to_process.push(remaining_backward);
+    }
+    if !remaining_forward.is_empty() {
+        to_process.push(remaining_forward);
+    }
+    if !remaining_rest.is_empty() {
+        to_process.push(remaining_rest);
+    }
+}
+
+/// The phases of one iteration. Normally, the backward reachable set is computed first
+/// (`Backward`, then `Forward`). If [`SccConfig::reversed`] is set, the forward reachable
+/// set is computed first (`ReversedForward`, then `ReversedBackward`).
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 enum Step<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm> {
     Idle,
     Trimming(Box<Step1>),
     Backward(Box<Step2<BWD>>),
     Forward(Box<Step3<FWD>>),
+    ReversedForward(Box<Step2<FWD>>),
+    ReversedBackward(Box<Step3<BWD>>),
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct Step1 {
+    full_universe: GraphColoredVertices,
     universe: TrimComputation,
 }
 
+/// Computing the first reachable set of the pivot.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-struct Step2<BWD: ReachabilityAlgorithm> {
+struct Step2<R: ReachabilityAlgorithm> {
     pivot: GraphColoredVertices,
     universe: GraphColoredVertices,
-    backward: BWD,
+    first: R,
 }
 
+/// Computing the second reachable set of the pivot (the first one is already known).
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-struct Step3<FWD> {
+struct Step3<R> {
+    pivot: GraphColoredVertices,
     universe: GraphColoredVertices,
-    backward: GraphColoredVertices,
-    forward: FWD,
+    first: GraphColoredVertices,
+    second: R,
 }
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 struct IterationResult {
     universe: GraphColoredVertices,
-    forward: GraphColoredVertices,
-    backward: GraphColoredVertices,
+    first: GraphColoredVertices,
+    second: GraphColoredVertices,
     scc: Option<GraphColoredVertices>,
 }
 
 impl Step1 {
-    pub fn new(context: &SccConfig, set: GraphColoredVertices) -> Step1 {
+    pub fn new(context: &SccConfig, set: GraphColoredVertices, initial: bool) -> Step1 {
         Step1 {
-            universe: context.should_trim.build_computation(&context.graph, set),
+            full_universe: set.clone(),
+            universe: context
+                .trim_setting(initial)
+                .build_computation(context.reachability_config(context.graph.clone()), set),
         }
     }
 
-    pub fn try_advance<BWD: ReachabilityAlgorithm>(
+    pub fn try_advance<R: ReachabilityAlgorithm>(
         &mut self,
         context: &SccConfig,
-    ) -> Completable<Option<Step2<BWD>>> {
+    ) -> Completable<Option<Step2<R>>> {
         let universe = self.universe.try_compute()?;
 
         if universe.is_empty() {
-            debug!("Candidate set empty after trimming.");
+            debug!(
+                "{}Candidate set empty after trimming.",
+                LogLabel(&context.label)
+            );
             return Ok(None);
         }
 
         let Some(universe) = context.apply_long_lived_filter(&universe) else {
-            debug!("Candidate set empty after trimming and long-term filtering.");
+            debug!(
+                "{}Candidate set empty after trimming and long-term filtering.",
+                LogLabel(&context.label)
+            );
             return Ok(None);
         };
 
         let graph = context.graph.restrict(&universe);
-        let pivot = universe.pick_vertex();
+        let pivot = context.pick_pivot(&universe);
         Ok(Some(Step2 {
-            backward: BWD::configure(&graph, pivot.clone()),
+            first: R::configure(context.reachability_config(graph), pivot.clone()),
             universe,
             pivot,
         }))
     }
 }
 
-impl<BWD: ReachabilityAlgorithm> Step2<BWD> {
-    pub fn try_advance<FWD: ReachabilityAlgorithm>(
+impl<R: ReachabilityAlgorithm> Step2<R> {
+    pub fn try_advance<S: ReachabilityAlgorithm>(
         &mut self,
         context: &SccConfig,
-    ) -> Completable<Step3<FWD>> {
-        let backward = self.backward.try_compute()?;
+    ) -> Completable<Step3<S>> {
+        let first = self.first.try_compute()?;
         let graph = context.graph.restrict(&self.universe);
 
         let mut result = Step3 {
-            forward: FWD::configure(&graph, self.pivot.clone()),
+            second: S::configure(context.reachability_config(graph), self.pivot.clone()),
+            pivot: self.pivot.clone(),
             universe: context.graph.mk_empty_colored_vertices(),
-            backward,
+            first,
         };
 
         std::mem::swap(&mut result.universe, &mut self.universe);
@@ -231,21 +402,25 @@ impl<BWD: ReachabilityAlgorithm> Step2<BWD> {
     }
 }
 
-impl<FWD: ReachabilityAlgorithm> Step3<FWD> {
+impl<R: ReachabilityAlgorithm> Step3<R> {
     pub fn try_advance(&mut self, context: &SccConfig) -> Completable<IterationResult> {
-        let forward = self.forward.try_compute()?;
-        let scc = forward.intersect(&self.backward);
-        debug!("Extracted raw SCC ({})", log_set(&scc));
+        let second = self.second.try_compute()?;
+        let scc = second.intersect(&self.first);
+        debug!(
+            "{}Extracted raw SCC ({})",
+            LogLabel(&context.label),
+            log_set(&scc)
+        );
 
         let mut result = IterationResult {
             universe: context.graph.mk_empty_colored_vertices(),
-            backward: context.graph.mk_empty_colored_vertices(),
-            scc: filter_scc(context, scc),
-            forward,
+            first: context.graph.mk_empty_colored_vertices(),
+            scc: filter_scc(context, scc, &self.universe),
+            second,
         };
 
         std::mem::swap(&mut result.universe, &mut self.universe);
-        std::mem::swap(&mut result.backward, &mut self.backward);
+        std::mem::swap(&mut result.first, &mut self.first);
 
         Ok(result)
     }
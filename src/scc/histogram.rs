@@ -0,0 +1,104 @@
+use crate::scc::{ChainState, ChainStep, SccConfig};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use cancel_this::is_cancelled;
+use computation_process::Incomplete::Suspended;
+use computation_process::{Completable, ComputationStep, GeneratorStep};
+use num_bigint::BigUint;
+use std::collections::BTreeMap;
+
+/// Internal state of the [`SccSizeHistogram`](crate::scc::SccSizeHistogram) computation.
+///
+/// It consists of the state of the underlying [`ChainScc`](crate::scc::ChainScc) generator
+/// and the histogram of the SCCs reported so far.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SccHistogramState {
+    scc: ChainState,
+    pending: Option<GraphColoredVertices>,
+    histogram: BTreeMap<BigUint, usize>,
+}
+
+impl SccHistogramState {
+    /// The histogram of the SCCs that were reported so far.
+    pub fn histogram(&self) -> &BTreeMap<BigUint, usize> {
+        &self.histogram
+    }
+
+    /// Add the `pending` SCC (of every color) to the histogram.
+    ///
+    /// The processed colors are removed from `pending` one by one, such that the computation
+    /// can be resumed if it is cancelled in the middle of a large SCC.
+    fn record_pending(&mut self) -> Completable<()> {
+        while self.pending.is_some() {
+            is_cancelled!()?;
+            let Some(scc) = self.pending.take() else {
+                break;
+            };
+            let colors = scc.colors();
+            let vertices = scc.vertices().exact_cardinality();
+            let color_count = colors.exact_cardinality();
+            if &vertices * &color_count == scc.exact_cardinality() {
+                // Fast path: the SCC has the same vertices in every color.
+                let count = usize::try_from(&color_count).unwrap_or(usize::MAX);
+                self.add(vertices, count);
+            } else {
+                let color = colors.pick_singleton();
+                self.add(scc.intersect_colors(&color).exact_cardinality(), 1);
+                let rest = scc.minus_colors(&color);
+                if !rest.is_empty() {
+                    self.pending = Some(rest);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn add(&mut self, size: BigUint, count: usize) {
+        let entry = self.histogram.entry(size).or_default();
+        *entry = entry.saturating_add(count);
+    }
+}
+
+impl From<&SymbolicAsyncGraph> for SccHistogramState {
+    fn from(value: &SymbolicAsyncGraph) -> Self {
+        SccHistogramState::from(value.mk_unit_colored_vertices())
+    }
+}
+
+impl From<&GraphColoredVertices> for SccHistogramState {
+    fn from(value: &GraphColoredVertices) -> Self {
+        SccHistogramState::from(value.clone())
+    }
+}
+
+impl From<GraphColoredVertices> for SccHistogramState {
+    fn from(value: GraphColoredVertices) -> Self {
+        SccHistogramState {
+            scc: ChainState::from(value),
+            pending: None,
+            histogram: BTreeMap::new(),
+        }
+    }
+}
+
+/// Step implementation of the [`SccSizeHistogram`](crate::scc::SccSizeHistogram) computation.
+///
+/// Each step advances the underlying [`ChainStep`] and records the reported SCC (if any).
+pub struct SccHistogramStep;
+
+impl ComputationStep<SccConfig, SccHistogramState, BTreeMap<BigUint, usize>> for SccHistogramStep {
+    fn step(
+        context: &SccConfig,
+        state: &mut SccHistogramState,
+    ) -> Completable<BTreeMap<BigUint, usize>> {
+        state.record_pending()?;
+        match ChainStep::step(context, &mut state.scc)? {
+            Some(scc) => {
+                state.pending = Some(scc);
+                state.record_pending()?;
+                Err(Suspended)
+            }
+            None => Ok(state.histogram.clone()),
+        }
+    }
+}
@@ -10,8 +10,23 @@
 //! - [`ChainScc`]: Chain-based algorithm that uses backward reachability to find basins
 //!   and then forward reachability within each basin to find SCCs. Can sometimes handle
 //!   larger networks.
+//! - [`SkeletonScc`]: Spine-set ("skeleton") based algorithm that performs a linear number
+//!   of symbolic steps. Mostly useful as an alternative for hard instances and as a reference.
 //!
-//! Both algorithms only report **non-trivial SCCs** (containing more than one state).
+//! All algorithms only report **non-trivial SCCs** (containing more than one state).
+//!
+//! To decompose only a part of the state space (ignoring transitions that leave it), use
+//! [`sccs_in_region`].
+//!
+//! To drive a progress indicator during long decompositions, every generator has a
+//! `*WithProgress` variant (e.g., [`ChainSccWithProgress`]) that can also yield progress items
+//! between the SCCs (see [`SccConfig::heartbeat`]).
+//!
+//! For a quick structural summary, [`SccSizeHistogram`] counts the SCCs of each size without
+//! retaining them.
+//!
+//! The reported components can be further arranged into an [`SccQuotient`], which can be
+//! exported to Graphviz using [`to_dot`].
 //!
 //! # Configuration
 //!
@@ -19,7 +34,11 @@
 //!
 //! - **Trimming**: Remove trivial sink/source states before SCC computation
 //! - **Long-lived filtering**: Only report SCCs that cannot be escaped by updating
-//!   a single variable
+//!   a single variable (a stricter version is available as [`retain_long_lived_k`])
+//! - **Terminal filtering**: Only report SCCs that cannot be escaped at all
+//!   (see [`SccConfig::terminal_only`])
+//!
+//! Both can also be applied as a standalone preprocessing pass using [`CoreReduction`].
 //!
 //! # Example
 //!
@@ -43,22 +62,39 @@
 //! ```
 
 mod chain;
+mod core_reduction;
 mod fwd_bwd;
+mod histogram;
+mod progress;
+mod quotient;
 mod scc_config;
+mod skeleton;
 
 #[cfg(test)]
 mod tests;
 
+use crate::enumeration::export_states;
 use crate::reachability::{
     BackwardReachability, BackwardReachabilityBfs, ForwardReachability, ForwardReachabilityBfs,
 };
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
-use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
+use cancel_this::{Cancellable, is_cancelled};
 pub use chain::{ChainState, ChainStep};
-use computation_process::{GenAlgorithm, Generator};
+use computation_process::{Algorithm, Computation, GenAlgorithm, Generator, Stateful};
+pub use core_reduction::CoreReduction;
 pub use fwd_bwd::{FwdBwdState, FwdBwdStep};
-use log::info;
-pub use scc_config::SccConfig;
+pub use histogram::{SccHistogramState, SccHistogramStep};
+use log::{error, info};
+use num_bigint::BigUint;
+pub use progress::{SccItem, SccProgress, SccProgressState, SccProgressStep};
+pub use quotient::{SccQuotient, to_dot};
+pub use scc_config::{SccConfig, SccSettings};
+pub use skeleton::{SkeletonState, SkeletonStep};
+use std::collections::BTreeMap;
 
 /// A helper trait which allows us to use [`SccAlgorithm`] as shorthand for
 /// `GenAlgorithm<Context = SymbolicAsyncGraph, Output = GraphColoredVertices>`.
@@ -86,6 +122,14 @@ pub type FwdBwdScc = Generator<
     FwdBwdStep<ForwardReachability, BackwardReachability>,
 >;
 
+/// A variant of [`FwdBwdScc`] that yields [`SccItem`] values (see [`SccConfig::heartbeat`]).
+pub type FwdBwdSccWithProgress = Generator<
+    SccConfig,
+    FwdBwdState<ForwardReachability, BackwardReachability>,
+    SccItem,
+    SccProgressStep<FwdBwdStep<ForwardReachability, BackwardReachability>>,
+>;
+
 /// Variant of [`FwdBwdScc`] that uses BFS reachability. This is not very practical (the rigid
 /// BFS order is not required for `fwd-bwd` to work) and is mostly just intended for benchmarking.
 pub type FwdBwdSccBfs = Generator<
@@ -95,6 +139,14 @@ pub type FwdBwdSccBfs = Generator<
     FwdBwdStep<ForwardReachabilityBfs, BackwardReachabilityBfs>,
 >;
 
+/// A variant of [`FwdBwdSccBfs`] that yields [`SccItem`] values (see [`SccConfig::heartbeat`]).
+pub type FwdBwdSccBfsWithProgress = Generator<
+    SccConfig,
+    FwdBwdState<ForwardReachabilityBfs, BackwardReachabilityBfs>,
+    SccItem,
+    SccProgressStep<FwdBwdStep<ForwardReachabilityBfs, BackwardReachabilityBfs>>,
+>;
+
 /// An SCC detection algorithm that uses "chain-like" exploration. It is generally faster
 /// than the `fwd-bwd` algorithm, but not exclusively so. Generally, we recommend
 /// `chain` as the default SCC detection algorithm, but for hard instances it may be useful
@@ -115,14 +167,135 @@ pub type FwdBwdSccBfs = Generator<
 ///    of the trimmed set.
 pub type ChainScc = Generator<SccConfig, ChainState, GraphColoredVertices, ChainStep>;
 
-/// Remove colors that correspond to trivial and short-lived SCCs (if configured to do so).
-fn filter_scc(context: &SccConfig, scc: GraphColoredVertices) -> Option<GraphColoredVertices> {
+/// A variant of [`ChainScc`] that yields [`SccItem`] values, such that a consumer can observe
+/// the progress of long decompositions between the reported SCCs (see [`SccConfig::heartbeat`]).
+pub type ChainSccWithProgress =
+    Generator<SccConfig, ChainState, SccItem, SccProgressStep<ChainStep>>;
+
+/// An SCC detection algorithm based on spine-sets ("skeletons"), following
+/// [Finding strongly connected components in symbolic graphs](https://doi.org/10.1007/3-540-44829-2_22)
+/// by Gentilini, Piazza, and Policriti.
+///
+/// It performs a linear number of symbolic steps and is mostly intended as an alternative
+/// for hard instances and as a reference to validate the other algorithms against.
+/// The [`SccConfig::should_trim`] setting is ignored, because trimming would invalidate
+/// the spine-sets.
+///
+/// Basic algorithm idea:
+///  - Pick a pivot vertex (the end of the current spine-set, if any).
+///  - Compute the forward reachable set in BFS layers and a shortest path (new spine-set)
+///    from the pivot to the last layer.
+///  - The SCC is the backward reachable set of the pivot within the forward set.
+///  - Recursively continue in `ALL \ FWD` (with the rest of the old spine-set) and
+///    `FWD \ SCC` (with the rest of the new spine-set).
+pub type SkeletonScc = Generator<SccConfig, SkeletonState, GraphColoredVertices, SkeletonStep>;
+
+/// A variant of [`SkeletonScc`] that yields [`SccItem`] values (see [`SccConfig::heartbeat`]).
+pub type SkeletonSccWithProgress =
+    Generator<SccConfig, SkeletonState, SccItem, SccProgressStep<SkeletonStep>>;
+
+/// Compute a histogram of the sizes of all non-trivial SCCs, i.e., a map from the number of
+/// states of an SCC to the number of SCCs of that size.
+///
+/// The SCCs are enumerated using [`ChainScc`] (respecting the [`SccConfig`] filters), but they
+/// are not retained. For parametrized networks, the sizes are counted per color: an SCC that
+/// exists in `k` colors is counted `k` times (possibly with a different size in each color).
+/// Counts saturate at `usize::MAX`.
+pub type SccSizeHistogram =
+    Computation<SccConfig, SccHistogramState, BTreeMap<BigUint, usize>, SccHistogramStep>;
+
+/// Check whether the `graph` has at least one non-trivial SCC (for some color).
+///
+/// This runs [`ChainScc`] and stops at the first reported component, so it is typically much
+/// faster than full enumeration when a cycle is found early. For parametrized networks,
+/// the result is `true` if a non-trivial SCC exists for at least one color
+/// (see also [`colors_with_nontrivial_scc`]).
+pub fn has_nontrivial_scc(graph: &SymbolicAsyncGraph) -> Cancellable<bool> {
+    match ChainScc::configure(graph, graph).next() {
+        Some(scc) => scc.map(|_| true),
+        None => Ok(false),
+    }
+}
+
+/// Compute the colors of the `graph` in which at least one non-trivial SCC exists.
+///
+/// This runs [`ChainScc`] and stops as soon as every color has a non-trivial SCC.
+pub fn colors_with_nontrivial_scc(graph: &SymbolicAsyncGraph) -> Cancellable<GraphColors> {
+    let all_colors = graph.mk_unit_colors();
+    let mut colors = graph.mk_empty_colors();
+    for scc in ChainScc::configure(graph, graph) {
+        colors = colors.union(&scc?.colors());
+        if colors == all_colors {
+            break;
+        }
+    }
+    Ok(colors)
+}
+
+/// Enumerate the non-trivial SCCs of the `graph` restricted to the given `region`.
+///
+/// All transitions that leave the `region` are ignored, i.e., the result is the SCC
+/// decomposition of the subgraph induced by `region`. Hence, an SCC of the full `graph` that
+/// is only partially contained in `region` is split into (possibly trivial) components of the
+/// states that remain strongly connected within `region`. Since the whole [`SccConfig`] uses
+/// the restricted graph, the long-lived and terminal filters are evaluated within the `region`
+/// as well. The SCCs are enumerated using [`ChainScc`].
+pub fn sccs_in_region(graph: &SymbolicAsyncGraph, region: &GraphColoredVertices) -> ChainScc {
+    let restricted = graph.restrict(region);
+    ChainScc::configure(&restricted, region)
+}
+
+/// Compute the colors for which `set` is exactly one SCC of the `graph`, i.e., every pair of
+/// states of `set` is mutually reachable using only the states of `set`.
+///
+/// This is checked by selecting a pivot (for each color) and verifying that the forward and
+/// backward reachable sets of the pivot within `set` both cover the whole `set`. A single
+/// state is also an SCC (a trivial one). Only colors of `set` can appear in the result, since
+/// an empty set is not an SCC. Note that `set` does not have to be a maximal SCC:
+/// a cycle can be part of a larger component.
+pub fn scc_colors(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Cancellable<GraphColors> {
+    let within = graph.restrict(set);
+    let pivot = set.pick_vertex();
+    let forward = ForwardReachability::run(&within, pivot.clone())?;
+    let backward = BackwardReachability::run(&within, pivot)?;
+    let invalid = set.minus(&forward).union(&set.minus(&backward)).colors();
+    Ok(set.colors().minus(&invalid))
+}
+
+/// Check whether `set` is exactly one SCC of the `graph` (see [`scc_colors`]).
+///
+/// For parametrized networks, the property must hold for all colors of `set`. An empty set is
+/// not an SCC.
+pub fn is_scc(graph: &SymbolicAsyncGraph, set: &GraphColoredVertices) -> Cancellable<bool> {
+    Ok(!set.is_empty() && scc_colors(graph, set)? == set.colors())
+}
+
+/// Compute the colors in which the `set` has more than one state (i.e., the colors in which
+/// a component is non-trivial).
+///
+/// All SCC algorithms in this module only emit SCCs restricted to such colors, so for their
+/// results, this is always equal to `scc.colors()`. The function is mostly useful for sets
+/// that did not pass through the SCC filters (e.g., components computed using [`scc_colors`]).
+pub fn nontrivial_colors(set: &GraphColoredVertices) -> GraphColors {
+    set.minus(&set.pick_vertex()).colors()
+}
+
+/// Remove colors that correspond to trivial, short-lived, and non-terminal SCCs (if configured
+/// to do so). The `universe` is the set in which the SCC was computed (see [`verify_scc`]).
+fn filter_scc(
+    context: &SccConfig,
+    scc: GraphColoredVertices,
+    universe: &GraphColoredVertices,
+) -> Option<GraphColoredVertices> {
     // First, remove all colors in which the SCC is trivial.
-    let valid_colors = scc.minus(&scc.pick_vertex()).colors();
+    let valid_colors = nontrivial_colors(&scc);
     let non_trivial_scc = scc.intersect_colors(&valid_colors);
 
     if non_trivial_scc.is_empty() {
-        info!("The SCC is trivial.");
+        info!("{}The SCC is trivial.", LogLabel(&context.label));
         return None;
     }
 
@@ -133,13 +306,90 @@ fn filter_scc(context: &SccConfig, scc: GraphColoredVertices) -> Option<GraphCol
     };
 
     if long_lived_scc.is_empty() {
-        info!("The SCC is short-lived.");
+        info!("{}The SCC is short-lived.", LogLabel(&context.label));
+        return None;
+    }
+
+    let long_lived_scc = if context.terminal_only {
+        let escaping = context.graph.can_post_out(&long_lived_scc);
+        long_lived_scc.minus_colors(&escaping.colors())
+    } else {
+        long_lived_scc
+    };
+
+    if long_lived_scc.is_empty() {
+        info!("{}The SCC is not terminal.", LogLabel(&context.label));
         return None;
     }
 
+    if cfg!(debug_assertions) && context.verify {
+        verify_scc(context, &long_lived_scc, universe);
+    }
+
     Some(long_lived_scc)
 }
 
+/// Check that `scc` is exactly the intersection of the forward and backward reachable sets
+/// of one of its vertices (for each color) and panic otherwise.
+///
+/// The reference sets are computed in [`SccConfig::graph`] restricted to `universe`, i.e., the
+/// set in which the SCC was found (an SCC of a sub-graph can be a strict subset of an SCC in
+/// the full graph). The check is skipped if the reference computation is cancelled.
+fn verify_scc(context: &SccConfig, scc: &GraphColoredVertices, universe: &GraphColoredVertices) {
+    let pivot = scc.pick_vertex();
+    let config = context.reachability_config(context.graph.restrict(universe));
+    let Ok(forward) = ForwardReachability::run(config.clone(), pivot.clone()) else {
+        return;
+    };
+    let Ok(backward) = BackwardReachability::run(config, pivot) else {
+        return;
+    };
+    let expected = forward.intersect(&backward);
+    if &expected != scc {
+        error!(
+            "{}SCC verification failed. Reported ({}); expected ({}).",
+            LogLabel(&context.label),
+            log_set(scc),
+            log_set(&expected),
+        );
+        panic!(
+            "SCC verification failed. Reported {:?}; expected {:?}.",
+            export_states(&context.graph, scc),
+            export_states(&context.graph, &expected),
+        );
+    }
+}
+
+/// If long-lived filtering is enabled, compute the colors in which the union of the remaining
+/// `sets` is long-lived. Returns `None` if no colors can be removed this way.
+///
+/// This is sound, because if a variable update can escape the whole union, it can also escape
+/// any of its subsets (i.e., none of the remaining sets can contain a long-lived SCC). Hence, the
+/// generators can stop immediately once only short-lived states remain.
+fn long_lived_remainder_colors<'a>(
+    context: &SccConfig,
+    sets: impl Iterator<Item = &'a GraphColoredVertices>,
+) -> Option<GraphColors> {
+    if !context.filter_long_lived {
+        return None;
+    }
+
+    let mut remainder = context.graph.mk_empty_colored_vertices();
+    for set in sets {
+        remainder = remainder.union(set);
+    }
+    let colors = retain_long_lived(&context.graph, &remainder).colors();
+    if colors == remainder.colors() {
+        None
+    } else {
+        info!(
+            "{}Removing short-lived colors from the remaining sets.",
+            LogLabel(&context.label)
+        );
+        Some(colors)
+    }
+}
+
 /// Return a subset of states that are long-lived, meaning the set cannot be escaped by updating a
 /// single variable. This is evaluated per-color, i.e., each color is either fully retained
 /// or fully removed.
@@ -173,3 +423,37 @@ fn retain_long_lived(
         set.intersect_colors(&safe_colors)
     }
 }
+
+/// Generalization of the long-lived check to longer sequences of single-variable updates.
+///
+/// In the first round, this is exactly the per-color check of [`SccConfig::filter_long_lived`].
+/// Every subsequent round removes the states that can escape the current set with a single
+/// update (i.e., states that can escape using a longer sequence of updates) and repeats
+/// the per-color check on the rest. The computation stops after `k` rounds, or once the set
+/// is stable if `k` is `None`.
+///
+/// Hence, `Some(0)` returns the `set` unchanged, `Some(1)` keeps the default long-lived
+/// behavior, and `None` results in the greatest forward trap contained in `set` (as computed
+/// by [`ForwardTrap`](crate::trapping::ForwardTrap)): a stable set has no escaping states,
+/// and a non-empty trap never fails the per-color check.
+pub fn retain_long_lived_k(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    k: Option<usize>,
+) -> Cancellable<GraphColoredVertices> {
+    if k == Some(0) {
+        return Ok(set.clone());
+    }
+    let mut result = retain_long_lived(graph, set);
+    let mut round = 1;
+    while k.is_none_or(|k| round < k) {
+        is_cancelled!()?;
+        let reduced = retain_long_lived(graph, &result.minus(&graph.can_post_out(&result)));
+        if reduced == result {
+            break;
+        }
+        result = reduced;
+        round += 1;
+    }
+    Ok(result)
+}
@@ -0,0 +1,84 @@
+use crate::reachability::ReachabilityAlgorithm;
+use crate::scc::{ChainState, FwdBwdState, SccConfig, SkeletonState};
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use computation_process::Incomplete::Suspended;
+use computation_process::{Completable, GeneratorStep};
+use num_bigint::BigUint;
+use std::marker::PhantomData;
+
+/// An item of the SCC generators with progress reporting (e.g.,
+/// [`ChainSccWithProgress`](crate::scc::ChainSccWithProgress)).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SccItem {
+    /// The progress of the decomposition after an internal step that did not produce an SCC
+    /// (only emitted if [`SccConfig::heartbeat`] is enabled).
+    Progress(SccProgress),
+    /// A non-trivial SCC (the same as an item of the underlying SCC generator).
+    Scc(GraphColoredVertices),
+}
+
+/// A snapshot of the progress of an SCC decomposition (see [`SccItem::Progress`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SccProgress {
+    /// The number of vertices that still need to be processed
+    /// (see [`ChainState::remaining_cardinality`]).
+    pub remaining_cardinality: BigUint,
+    /// The total symbolic size (BDD nodes) of the sets that still need to be processed
+    /// (see [`ChainState::remaining_symbolic_size`]).
+    pub remaining_symbolic_size: usize,
+}
+
+/// The state of an SCC algorithm that can report its [`SccProgress`].
+pub trait SccProgressState {
+    /// A snapshot of the current progress of the decomposition.
+    fn progress(&self) -> SccProgress;
+}
+
+impl<FWD: ReachabilityAlgorithm, BWD: ReachabilityAlgorithm> SccProgressState
+    for FwdBwdState<FWD, BWD>
+{
+    fn progress(&self) -> SccProgress {
+        SccProgress {
+            remaining_cardinality: self.remaining_cardinality(),
+            remaining_symbolic_size: self.remaining_symbolic_size(),
+        }
+    }
+}
+
+impl SccProgressState for ChainState {
+    fn progress(&self) -> SccProgress {
+        SccProgress {
+            remaining_cardinality: self.remaining_cardinality(),
+            remaining_symbolic_size: self.remaining_symbolic_size(),
+        }
+    }
+}
+
+impl SccProgressState for SkeletonState {
+    fn progress(&self) -> SccProgress {
+        SccProgress {
+            remaining_cardinality: self.remaining_cardinality(),
+            remaining_symbolic_size: self.remaining_symbolic_size(),
+        }
+    }
+}
+
+/// Step implementation that wraps the `STEP` of an SCC algorithm and yields [`SccItem`] values
+/// instead of plain SCCs (see [`SccConfig::heartbeat`]).
+pub struct SccProgressStep<STEP> {
+    _phantom: PhantomData<STEP>,
+}
+
+impl<STATE, STEP> GeneratorStep<SccConfig, STATE, SccItem> for SccProgressStep<STEP>
+where
+    STATE: SccProgressState,
+    STEP: GeneratorStep<SccConfig, STATE, GraphColoredVertices>,
+{
+    fn step(context: &SccConfig, state: &mut STATE) -> Completable<Option<SccItem>> {
+        match STEP::step(context, state) {
+            Ok(scc) => Ok(scc.map(SccItem::Scc)),
+            Err(Suspended) if context.heartbeat => Ok(Some(SccItem::Progress(state.progress()))),
+            Err(error) => Err(error),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use std::fmt::Write;
+
+/// A quotient (condensation) of a [`SymbolicAsyncGraph`] with respect to a list
+/// of disjoint components (typically SCCs).
+///
+/// The quotient contains an edge `i -> j` if there is a transition from component `i` into
+/// component `j` (for at least one color). A component is *terminal* if no transition leaves
+/// it (for any color), i.e., a terminal SCC is an attractor.
+///
+/// Note that SCC generators in this crate only report non-trivial SCCs. If the quotient is
+/// built from such output, it only captures direct transitions between the reported components.
+#[derive(Clone, Debug)]
+pub struct SccQuotient {
+    components: Vec<GraphColoredVertices>,
+    edges: Vec<(usize, usize)>,
+    terminal: Vec<bool>,
+}
+
+impl SccQuotient {
+    /// Build the quotient of `graph` with respect to the given `components`.
+    pub fn new(graph: &SymbolicAsyncGraph, components: Vec<GraphColoredVertices>) -> SccQuotient {
+        let mut edges = Vec::new();
+        let mut terminal = Vec::new();
+        for (i, component) in components.iter().enumerate() {
+            let mut successors = graph.mk_empty_colored_vertices();
+            for var in graph.variables() {
+                successors = successors.union(&graph.var_post_out(var, component));
+            }
+            terminal.push(successors.is_empty());
+            for (j, target) in components.iter().enumerate() {
+                if i != j && !successors.intersect(target).is_empty() {
+                    edges.push((i, j));
+                }
+            }
+        }
+
+        SccQuotient {
+            components,
+            edges,
+            terminal,
+        }
+    }
+
+    /// The components (nodes) of this quotient.
+    pub fn components(&self) -> &[GraphColoredVertices] {
+        &self.components
+    }
+
+    /// The edges of this quotient, represented as pairs of component indices.
+    pub fn edges(&self) -> &[(usize, usize)] {
+        &self.edges
+    }
+
+    /// True if no transition leaves the component with the given index.
+    pub fn is_terminal(&self, index: usize) -> bool {
+        self.terminal[index]
+    }
+}
+
+/// Export the [`SccQuotient`] as a Graphviz `digraph`.
+///
+/// Each node is labeled with the cardinality of its component. Terminal components
+/// are drawn as `doublecircle`, other components as `circle`.
+pub fn to_dot(quotient: &SccQuotient) -> String {
+    let mut dot = String::from("digraph quotient {\n");
+    for (i, component) in quotient.components().iter().enumerate() {
+        let shape = if quotient.is_terminal(i) {
+            "doublecircle"
+        } else {
+            "circle"
+        };
+        writeln!(
+            dot,
+            "  {i} [label=\"{}\", shape={shape}];",
+            component.exact_cardinality()
+        )
+        .unwrap();
+    }
+    for (source, target) in quotient.edges() {
+        writeln!(dot, "  {source} -> {target};").unwrap();
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scc::{SccQuotient, to_dot};
+    use crate::test_utils::llm_example_network::create_test_network;
+    use crate::test_utils::llm_example_network::sets::{ATTRACTOR_1, ATTRACTOR_2};
+    use crate::test_utils::llm_example_network::states::{S011, S100};
+    use crate::test_utils::mk_states;
+
+    #[test]
+    fn example_network_to_dot() {
+        let graph = create_test_network();
+        let components = vec![
+            mk_states(&graph, &[S011]),
+            mk_states(&graph, &[S100]),
+            mk_states(&graph, ATTRACTOR_1),
+            mk_states(&graph, ATTRACTOR_2),
+        ];
+        let quotient = SccQuotient::new(&graph, components);
+        assert_eq!(quotient.edges(), &[(0, 3), (1, 2), (1, 3)]);
+        assert!(!quotient.is_terminal(0));
+        assert!(quotient.is_terminal(2));
+        assert!(quotient.is_terminal(3));
+
+        let dot = to_dot(&quotient);
+        assert!(dot.starts_with("digraph"));
+        assert!(dot.contains("3 [label=\"2\", shape=doublecircle];"));
+        assert!(dot.contains("0 [label=\"1\", shape=circle];"));
+        assert!(dot.contains("1 -> 3;"));
+    }
+}
@@ -1,7 +1,16 @@
-use crate::scc::retain_long_lived;
+use crate::enumeration::pick_smallest_vertex;
+use crate::reachability::{ForwardReachability, PeakTracker, ReachabilityConfig};
+use crate::scc::{CoreReduction, retain_long_lived};
+use crate::trapping::restrict_to_forward_trap;
 use crate::trimming::TrimSetting;
+use biodivine_lib_param_bn::VariableId;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
-use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
+use cancel_this::Cancellable;
+use computation_process::Algorithm;
+use std::collections::BTreeSet;
 
 /// A configuration object for various SCC detection problems.
 #[derive(Clone)]
@@ -15,12 +24,174 @@ pub struct SccConfig {
     /// If you are only interested in a subset of SCCs, you want to instead limit the
     /// initial set used by the algorithm.
     pub graph: SymbolicAsyncGraph,
+    /// The set of variables that can be updated during SCC detection (default: all variables).
+    ///
+    /// The remaining variables are frozen, i.e., the SCCs are computed in the graph where only
+    /// the transitions of these variables are considered. If the set is empty, every state is
+    /// a trivial SCC and nothing is reported. Note that [`SccConfig::filter_long_lived`] still
+    /// considers the updates of all variables.
+    pub active_variables: BTreeSet<VariableId>,
     /// Indicate that the algorithm should try to trim trivial components (default: both).
+    ///
+    /// The trimming is applied to every universe before a pivot is selected, i.e., to the
+    /// initial universe as well as to every remaining set produced by the decomposition.
+    /// Trimmed states are never part of a non-trivial SCC, hence this setting can change
+    /// the number of internal steps, but never the set of reported SCCs.
     pub should_trim: TrimSetting,
     /// Indicate that only long-lived components should be reported.
     ///
     /// A component is long-lived if it cannot be escaped by updating a single variable.
     pub filter_long_lived: bool,
+    /// Indicate that only terminal (bottom) components should be reported (default: false).
+    ///
+    /// A component is terminal if no transition (of any variable) leaves it. For parametrized
+    /// networks, the colors in which a component is not terminal are removed. Since only
+    /// non-trivial components are reported, terminal components are exactly the attractors
+    /// that are not fixed points (see also
+    /// [`XieBeerelAttractors`](crate::attractor::XieBeerelAttractors)).
+    pub terminal_only: bool,
+    /// Select pivots deterministically (default: false).
+    ///
+    /// If enabled, the pivot is the lexicographically smallest vertex of the candidate set
+    /// (see [`pick_smallest_vertex`]) instead of a vertex selected by
+    /// [`GraphColoredVertices::pick_vertex`], which depends on the BDD structure. The set of
+    /// reported SCCs is unchanged, but their order is stable across runs and versions. This
+    /// requires a few extra symbolic operations per pivot, which is typically negligible.
+    pub deterministic: bool,
+    /// Restrict the initial universe to its greatest forward trap before the search
+    /// (default: false).
+    ///
+    /// If the initial universe is not a forward trap, some of the reported components may not be
+    /// SCCs of the full graph. If enabled, the states that can escape the universe are removed
+    /// before the exploration starts. If the universe is already a forward trap, this has
+    /// no effect.
+    pub auto_trap: bool,
+    /// If set, the initial universe is restricted to the states that are forward-reachable
+    /// from this seed before the search (default: `None`).
+    ///
+    /// Only the SCCs within the reachable region are reported. Since such a region is
+    /// a forward trap, the reported components are also SCCs of the full graph. The region is
+    /// computed when the search starts, so it respects the final
+    /// [`SccConfig::active_variables`] regardless of the order in which the settings are updated.
+    pub reachable_from: Option<GraphColoredVertices>,
+    /// Trust that the initial universe is already a forward trap (default: false).
+    ///
+    /// If enabled, the initial trimming pass and the [`SccConfig::auto_trap`] reduction
+    /// are skipped. This avoids redundant work in pipelines where the universe is known
+    /// to be trapped (e.g., after ITGR). If the universe is not actually a forward trap,
+    /// the reported components may not be SCCs of the full graph.
+    pub assume_trapped: bool,
+    /// An optional pivot hint used for the first pivot selection of [`ChainScc`]
+    /// (default: `None`).
+    ///
+    /// This allows domain knowledge (e.g., a known attractor candidate) to bias the
+    /// exploration. The hint is ignored if it does not intersect the (trimmed) initial universe.
+    /// The set of reported SCCs is unchanged. Other algorithms ignore this setting.
+    ///
+    /// [`ChainScc`]: crate::scc::ChainScc
+    pub initial_pivot_hint: Option<GraphColoredVertices>,
+    /// Explore the graph in the reversed direction (default: false).
+    ///
+    /// If enabled, [`FwdBwdScc`] computes the forward reachable set of each pivot first, and
+    /// [`ChainScc`] computes the forward ("reversed basin") and then the backward reachable set
+    /// (including the pivot hints, which are swapped accordingly). The reported SCCs are
+    /// the same as without reversal, but the performance can differ.
+    ///
+    /// [`FwdBwdScc`]: crate::scc::FwdBwdScc
+    /// [`ChainScc`]: crate::scc::ChainScc
+    pub reversed: bool,
+    /// Verify each reported SCC against a slow reference in debug builds (default: false).
+    ///
+    /// If enabled, each reported component is compared with the intersection of the forward
+    /// and backward reachable sets of one of its vertices (computed in [`SccConfig::graph`]
+    /// restricted to the set in which the component was found).
+    /// On mismatch, the algorithm panics. In release builds, this setting has no effect.
+    pub verify: bool,
+    /// Report the progress of the decomposition between the SCCs (default: false).
+    ///
+    /// This only affects the generators that yield [`SccItem`] values (the `*WithProgress`
+    /// variants of all SCC generators, e.g., [`ChainSccWithProgress`]): if enabled,
+    /// an [`SccItem::Progress`] item is emitted after every internal step that did not produce
+    /// an SCC. Otherwise, only [`SccItem::Scc`] items are emitted. The plain SCC generators are
+    /// not affected.
+    ///
+    /// [`SccItem`]: crate::scc::SccItem
+    /// [`SccItem::Progress`]: crate::scc::SccItem::Progress
+    /// [`SccItem::Scc`]: crate::scc::SccItem::Scc
+    /// [`ChainSccWithProgress`]: crate::scc::ChainSccWithProgress
+    pub heartbeat: bool,
+    /// An optional tracker of the largest symbolic size (BDD nodes) of the intermediate sets
+    /// observed by the reachability and trimming procedures (default: `None`). See
+    /// [`SccConfig::track_peak_symbolic_size`].
+    ///
+    /// The tracker is not serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub peak_tracker: Option<PeakTracker>,
+    /// An optional label of this run which is used as a prefix of all log messages
+    /// (default: `None`). This is useful to distinguish logs of concurrent runs.
+    pub label: Option<String>,
+}
+
+/// The graph-independent settings of an [`SccConfig`].
+///
+/// Unlike [`SccConfig`], this object does not contain the [`SymbolicAsyncGraph`] (or any
+/// symbolic sets), so it can be stored in a configuration file and later re-attached to
+/// a graph using [`SccSettings::rehydrate`]. The [`SccConfig::reachable_from`] and
+/// [`SccConfig::initial_pivot_hint`] are not included and are `None` after rehydration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SccSettings {
+    /// See [`SccConfig::active_variables`].
+    pub active_variables: BTreeSet<VariableId>,
+    /// See [`SccConfig::should_trim`].
+    pub should_trim: TrimSetting,
+    /// See [`SccConfig::filter_long_lived`].
+    pub filter_long_lived: bool,
+    /// See [`SccConfig::terminal_only`].
+    pub terminal_only: bool,
+    /// See [`SccConfig::deterministic`].
+    pub deterministic: bool,
+    /// See [`SccConfig::auto_trap`].
+    pub auto_trap: bool,
+    /// See [`SccConfig::assume_trapped`].
+    pub assume_trapped: bool,
+    /// See [`SccConfig::reversed`].
+    pub reversed: bool,
+    /// See [`SccConfig::verify`].
+    pub verify: bool,
+    /// See [`SccConfig::heartbeat`].
+    pub heartbeat: bool,
+    /// See [`SccConfig::label`].
+    pub label: Option<String>,
+}
+
+impl SccSettings {
+    /// Create an [`SccConfig`] for the given `graph` using these settings.
+    ///
+    /// The `graph` should be the same as (or compatible with) the graph of the
+    /// original config, since the settings refer to its variables.
+    pub fn rehydrate(self, graph: SymbolicAsyncGraph) -> SccConfig {
+        SccConfig {
+            active_variables: self.active_variables,
+            should_trim: self.should_trim,
+            filter_long_lived: self.filter_long_lived,
+            terminal_only: self.terminal_only,
+            deterministic: self.deterministic,
+            auto_trap: self.auto_trap,
+            assume_trapped: self.assume_trapped,
+            reversed: self.reversed,
+            verify: self.verify,
+            heartbeat: self.heartbeat,
+            label: self.label,
+            ..SccConfig::new(graph)
+        }
+    }
+}
+
+impl From<&SccConfig> for SccSettings {
+    fn from(value: &SccConfig) -> Self {
+        value.settings()
+    }
 }
 
 impl From<SymbolicAsyncGraph> for SccConfig {
@@ -40,9 +211,206 @@ impl SccConfig {
     /// with trimming enabled.
     pub fn new(graph: SymbolicAsyncGraph) -> SccConfig {
         SccConfig {
+            active_variables: graph.variables().collect(),
             graph,
             should_trim: TrimSetting::default(),
             filter_long_lived: false,
+            terminal_only: false,
+            deterministic: false,
+            auto_trap: false,
+            reachable_from: None,
+            assume_trapped: false,
+            initial_pivot_hint: None,
+            reversed: false,
+            verify: false,
+            heartbeat: false,
+            peak_tracker: None,
+            label: None,
+        }
+    }
+
+    /// Update the [`SccConfig::active_variables`] setting, such that only the given
+    /// `variables` can be updated.
+    pub fn with_active_variables(
+        mut self,
+        variables: impl IntoIterator<Item = VariableId>,
+    ) -> SccConfig {
+        self.active_variables = BTreeSet::from_iter(variables);
+        self
+    }
+
+    /// Update the [`SccConfig::should_trim`] setting.
+    pub fn should_trim(mut self, should_trim: TrimSetting) -> SccConfig {
+        self.should_trim = should_trim;
+        self
+    }
+
+    /// Update the [`SccConfig::terminal_only`] setting.
+    pub fn terminal_only(mut self, terminal_only: bool) -> SccConfig {
+        self.terminal_only = terminal_only;
+        self
+    }
+
+    /// Update the [`SccConfig::deterministic`] setting.
+    pub fn deterministic(mut self, deterministic: bool) -> SccConfig {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// Restrict the [`SccConfig::graph`] to the given `colors` (typically a single parameter
+    /// valuation, e.g., obtained using [`GraphColors::pick_singleton`]).
+    ///
+    /// For a single color, the algorithms then behave exactly as on the non-parametrized
+    /// network given by this valuation. Note that the initial set of the algorithm should be
+    /// created from the restricted graph (e.g., using `FwdBwdScc::configure(config.clone(), &config.graph)`), since states
+    /// of the other colors have no transitions in the restricted graph.
+    pub fn pin_color(mut self, colors: &GraphColors) -> SccConfig {
+        let pinned = self
+            .graph
+            .mk_unit_colored_vertices()
+            .intersect_colors(colors);
+        self.graph = self.graph.restrict(&pinned);
+        self
+    }
+
+    /// Update the [`SccConfig::auto_trap`] setting.
+    pub fn auto_trap(mut self, auto_trap: bool) -> SccConfig {
+        self.auto_trap = auto_trap;
+        self
+    }
+
+    /// Update the [`SccConfig::reachable_from`] setting.
+    pub fn reachable_from(mut self, seed: GraphColoredVertices) -> SccConfig {
+        self.reachable_from = Some(seed);
+        self
+    }
+
+    /// Update the [`SccConfig::assume_trapped`] setting.
+    pub fn assume_trapped(mut self, assume_trapped: bool) -> SccConfig {
+        self.assume_trapped = assume_trapped;
+        self
+    }
+
+    /// Update the [`SccConfig::initial_pivot_hint`] setting.
+    pub fn initial_pivot_hint(mut self, hint: GraphColoredVertices) -> SccConfig {
+        self.initial_pivot_hint = Some(hint);
+        self
+    }
+
+    /// Update the [`SccConfig::reversed`] setting.
+    pub fn reversed(mut self, reversed: bool) -> SccConfig {
+        self.reversed = reversed;
+        self
+    }
+
+    /// Update the [`SccConfig::verify`] setting.
+    pub fn verify(mut self, verify: bool) -> SccConfig {
+        self.verify = verify;
+        self
+    }
+
+    /// Update the [`SccConfig::heartbeat`] setting.
+    pub fn heartbeat(mut self, heartbeat: bool) -> SccConfig {
+        self.heartbeat = heartbeat;
+        self
+    }
+
+    /// Enable the [`SccConfig::peak_tracker`], such that the largest symbolic size of
+    /// the intermediate sets can be read using [`SccConfig::peak_symbolic_size`] after
+    /// the computation.
+    ///
+    /// The tracker is shared between all clones of this config.
+    pub fn track_peak_symbolic_size(mut self) -> SccConfig {
+        self.peak_tracker = Some(PeakTracker::default());
+        self
+    }
+
+    /// The largest symbolic size (BDD nodes) of the intermediate sets observed so far, or
+    /// zero if the [`SccConfig::peak_tracker`] is not enabled.
+    pub fn peak_symbolic_size(&self) -> usize {
+        self.peak_tracker
+            .as_ref()
+            .map(PeakTracker::get)
+            .unwrap_or(0)
+    }
+
+    /// Update the [`SccConfig::label`] setting.
+    pub fn label(mut self, label: impl Into<String>) -> SccConfig {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The graph-independent settings of this config (see [`SccSettings`]).
+    pub fn settings(&self) -> SccSettings {
+        SccSettings {
+            active_variables: self.active_variables.clone(),
+            should_trim: self.should_trim,
+            filter_long_lived: self.filter_long_lived,
+            terminal_only: self.terminal_only,
+            deterministic: self.deterministic,
+            auto_trap: self.auto_trap,
+            assume_trapped: self.assume_trapped,
+            reversed: self.reversed,
+            verify: self.verify,
+            heartbeat: self.heartbeat,
+            label: self.label.clone(),
+        }
+    }
+
+    /// Create a [`ReachabilityConfig`] for the given (typically restricted) `graph` that
+    /// inherits the relevant settings of this config.
+    pub fn reachability_config(&self, graph: SymbolicAsyncGraph) -> ReachabilityConfig {
+        ReachabilityConfig {
+            active_variables: self.active_variables.clone(),
+            peak_tracker: self.peak_tracker.clone(),
+            label: self.label.clone(),
+            ..ReachabilityConfig::new(graph)
+        }
+    }
+
+    /// Create a [`CoreReduction`] of the given `set` that uses the trimming and long-lived
+    /// filtering settings of this config.
+    pub fn core_reduction(&self, set: GraphColoredVertices) -> CoreReduction {
+        CoreReduction::new(self.reachability_config(self.graph.clone()), set)
+            .should_trim(self.should_trim)
+            .filter_long_lived(self.filter_long_lived)
+    }
+
+    /// Apply the [`SccConfig::reachable_from`] and [`SccConfig::auto_trap`] settings
+    /// to the initial universe `set`.
+    pub(crate) fn prepare_universe(
+        &self,
+        set: &GraphColoredVertices,
+    ) -> Cancellable<GraphColoredVertices> {
+        let mut set = set.clone();
+        if let Some(seed) = &self.reachable_from {
+            let reach_config = self.reachability_config(self.graph.clone());
+            let region = ForwardReachability::run(reach_config, seed.clone())?;
+            set = set.intersect(&region);
+        }
+        if self.auto_trap && !self.assume_trapped {
+            set = restrict_to_forward_trap(&self.graph, &set, &self.label)?;
+        }
+        Ok(set)
+    }
+
+    /// The trimming applied to a set before pivot selection, respecting the
+    /// [`SccConfig::assume_trapped`] setting for the `initial` universe.
+    pub(crate) fn trim_setting(&self, initial: bool) -> TrimSetting {
+        if initial && self.assume_trapped {
+            TrimSetting::None
+        } else {
+            self.should_trim
+        }
+    }
+
+    /// Pick a pivot vertex (for each color) from the given `set`, respecting the
+    /// [`SccConfig::deterministic`] setting.
+    pub fn pick_pivot(&self, set: &GraphColoredVertices) -> GraphColoredVertices {
+        if self.deterministic {
+            pick_smallest_vertex(&self.graph, set)
+        } else {
+            set.pick_vertex()
         }
     }
 
@@ -0,0 +1,252 @@
+use crate::reachability::BackwardReachability;
+use crate::scc::{SccConfig, filter_scc, long_lived_remainder_colors};
+use crate::{LogLabel, log_set};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use cancel_this::{Cancellable, is_cancelled};
+use computation_process::Incomplete::Suspended;
+use computation_process::{Algorithm, Completable, GeneratorStep};
+use log::{debug, info};
+use num_bigint::BigUint;
+
+/// Internal state for the skeleton-based SCC algorithm.
+///
+/// This struct tracks the pending work items, each consisting of a universe and
+/// a (possibly empty) spine-set within this universe.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SkeletonState {
+    to_process: Vec<SpineTask>,
+    prepared: bool,
+}
+
+/// Step implementation for the skeleton-based SCC algorithm.
+///
+/// This type implements the [`GeneratorStep`] trait for SCC enumeration.
+pub struct SkeletonStep;
+
+/// A universe together with a spine-set `(spine, spine_end)`: `spine` is a path in
+/// the universe (for each color) that ends in the vertex `spine_end`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct SpineTask {
+    universe: GraphColoredVertices,
+    spine: GraphColoredVertices,
+    spine_end: GraphColoredVertices,
+}
+
+impl SkeletonState {
+    /// The number of vertices that still need to be processed, i.e., the size of all pending
+    /// universes.
+    ///
+    /// The value is not a reliable estimate of the remaining work (some universes can be
+    /// resolved in a single iteration), but it never increases and can be used as a progress
+    /// hint while polling the computation.
+    pub fn remaining_cardinality(&self) -> BigUint {
+        self.to_process
+            .iter()
+            .map(|it| it.universe.exact_cardinality())
+            .sum()
+    }
+
+    /// The vertices that still need to be processed (see
+    /// [`SkeletonState::remaining_cardinality`]), or `None` if the decomposition is finished.
+    ///
+    /// The set never grows (for each color) as the decomposition proceeds.
+    pub fn remaining_universe(&self) -> Option<GraphColoredVertices> {
+        self.to_process
+            .iter()
+            .map(|it| it.universe.clone())
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// The total symbolic size (BDD nodes) of the sets that still need to be processed
+    /// (see [`SkeletonState::remaining_cardinality`]).
+    pub fn remaining_symbolic_size(&self) -> usize {
+        self.to_process
+            .iter()
+            .map(|it| it.universe.symbolic_size())
+            .sum()
+    }
+}
+
+impl From<&SymbolicAsyncGraph> for SkeletonState {
+    fn from(value: &SymbolicAsyncGraph) -> Self {
+        SkeletonState::from(value.mk_unit_colored_vertices())
+    }
+}
+
+impl From<&GraphColoredVertices> for SkeletonState {
+    fn from(value: &GraphColoredVertices) -> Self {
+        SkeletonState::from(value.clone())
+    }
+}
+
+impl From<GraphColoredVertices> for SkeletonState {
+    fn from(value: GraphColoredVertices) -> Self {
+        SkeletonState {
+            to_process: vec![SpineTask {
+                spine: value.minus(&value),
+                spine_end: value.minus(&value),
+                universe: value,
+            }],
+            prepared: false,
+        }
+    }
+}
+
+impl GeneratorStep<SccConfig, SkeletonState, GraphColoredVertices> for SkeletonStep {
+    fn step(
+        context: &SccConfig,
+        state: &mut SkeletonState,
+    ) -> Completable<Option<GraphColoredVertices>> {
+        if !state.prepared {
+            for task in state.to_process.iter_mut() {
+                task.universe = context.prepare_universe(&task.universe)?;
+            }
+            state.to_process.retain(|it| !it.universe.is_empty());
+            state.prepared = true;
+        }
+
+        if state.to_process.len() > 1
+            && let Some(colors) =
+                long_lived_remainder_colors(context, state.to_process.iter().map(|it| &it.universe))
+        {
+            state.to_process.retain_mut(|it| {
+                it.universe = it.universe.intersect_colors(&colors);
+                it.spine = it.spine.intersect_colors(&colors);
+                it.spine_end = it.spine_end.intersect_colors(&colors);
+                !it.universe.is_empty()
+            });
+        }
+
+        // The task stays on the stack until it is fully processed, such that it is not lost
+        // if the computation is cancelled (the step is then simply repeated).
+        let Some(task) = state.to_process.last() else {
+            // If there is nothing to process, we are done.
+            return Ok(None);
+        };
+
+        let pending = &state.to_process[..state.to_process.len() - 1];
+        info!(
+            "{}Start processing ({}); {} sets remaining (BDD nodes={})",
+            LogLabel(&context.label),
+            log_set(&task.universe),
+            pending.len(),
+            pending
+                .iter()
+                .map(|it| it.universe.symbolic_size())
+                .sum::<usize>()
+        );
+
+        // For colors without a spine-set, start from an arbitrary pivot.
+        let missing = task.universe.minus_colors(&task.spine_end.colors());
+        let pivot = task.spine_end.union(&context.pick_pivot(&missing));
+
+        let (forward, new_spine, new_spine_end) =
+            skeleton_forward(context, &task.universe, &pivot)?;
+        let scc_config = context.reachability_config(context.graph.restrict(&forward));
+        let scc = BackwardReachability::run(scc_config, pivot)?;
+        debug!(
+            "{}Extracted raw SCC ({}) using forward set ({}).",
+            LogLabel(&context.label),
+            log_set(&scc),
+            log_set(&forward),
+        );
+
+        let mut new_tasks = Vec::new();
+
+        // The part of the old spine outside the SCC leads to the SCC, hence it is not
+        // forward-reachable and stays in the remaining universe.
+        let remaining_rest = task.universe.minus(&forward);
+        if !remaining_rest.is_empty() {
+            let spine = task.spine.minus(&scc);
+            let spine_end = predecessors(context, &task.spine.intersect(&scc)).intersect(&spine);
+            new_tasks.push(SpineTask {
+                universe: remaining_rest,
+                spine,
+                spine_end,
+            });
+        }
+
+        // The part of the new spine outside the SCC is a path in the rest of the forward set.
+        let remaining_forward = forward.minus(&scc);
+        if !remaining_forward.is_empty() {
+            new_tasks.push(SpineTask {
+                universe: remaining_forward,
+                spine: new_spine.minus(&scc),
+                spine_end: new_spine_end.minus(&scc),
+            });
+        }
+
+        let scc = filter_scc(context, scc, &task.universe);
+
+        // The task is done: replace it with the new tasks.
+        state.to_process.pop();
+        state.to_process.extend(new_tasks);
+
+        if let Some(scc) = scc {
+            Ok(Some(scc))
+        } else {
+            Err(Suspended)
+        }
+    }
+}
+
+/// Compute the forward reachable set of `pivot` within `universe` in BFS layers, together
+/// with a new spine-set: a shortest path (for each color) from `pivot` to a vertex in
+/// the last BFS layer, and the final vertex of this path.
+fn skeleton_forward(
+    context: &SccConfig,
+    universe: &GraphColoredVertices,
+    pivot: &GraphColoredVertices,
+) -> Cancellable<(
+    GraphColoredVertices,
+    GraphColoredVertices,
+    GraphColoredVertices,
+)> {
+    let mut layers = Vec::new();
+    let mut forward = pivot.clone();
+    let mut layer = pivot.clone();
+    while !layer.is_empty() {
+        is_cancelled!()?;
+        let next = successors(context, &layer)
+            .intersect(universe)
+            .minus(&forward);
+        forward = forward.union(&next);
+        layers.push(layer);
+        layer = next;
+    }
+
+    // Walk the layers backwards: a color starts its path in its last non-empty layer,
+    // and then always continues with a predecessor of the last path vertex.
+    let mut spine = context.graph.mk_empty_colored_vertices();
+    let mut spine_end = context.graph.mk_empty_colored_vertices();
+    let mut last = context.graph.mk_empty_colored_vertices();
+    while let Some(layer) = layers.pop() {
+        is_cancelled!()?;
+        let fresh = context.pick_pivot(&layer.minus_colors(&spine.colors()));
+        let continued = context.pick_pivot(&predecessors(context, &last).intersect(&layer));
+        spine_end = spine_end.union(&fresh);
+        last = continued.union(&fresh);
+        spine = spine.union(&last);
+    }
+
+    Ok((forward, spine, spine_end))
+}
+
+/// Direct successors of `set` using the active variables of `context`.
+fn successors(context: &SccConfig, set: &GraphColoredVertices) -> GraphColoredVertices {
+    let mut result = context.graph.mk_empty_colored_vertices();
+    for var in &context.active_variables {
+        result = result.union(&context.graph.var_post(*var, set));
+    }
+    result
+}
+
+/// Direct predecessors of `set` using the active variables of `context`.
+fn predecessors(context: &SccConfig, set: &GraphColoredVertices) -> GraphColoredVertices {
+    let mut result = context.graph.mk_empty_colored_vertices();
+    for var in &context.active_variables {
+        result = result.union(&context.graph.var_pre(*var, set));
+    }
+    result
+}
@@ -4,12 +4,16 @@
 //! and deserialized mid-execution, and that deserialized generators can be resumed
 //! to produce the same results as uninterrupted generators.
 
-use crate::scc::{ChainScc, ChainState, FwdBwdScc, FwdBwdState, SccConfig};
+use crate::scc::{
+    ChainScc, ChainState, FwdBwdScc, FwdBwdState, SccConfig, SccSettings, SkeletonScc,
+    SkeletonState, SkeletonStep,
+};
 use crate::test_utils::llm_example_network::create_test_network;
 use crate::test_utils::llm_example_network::sets::ATTRACTOR_2;
 use crate::test_utils::{init_logger, mk_states};
-use cancel_this::Cancellable;
-use computation_process::Stateful;
+use crate::trimming::TrimSetting;
+use cancel_this::{CancelAtomic, Cancellable};
+use computation_process::{GeneratorStep, Incomplete, Stateful};
 use serde_json;
 
 // ========== Helper functions ==========
@@ -339,3 +343,69 @@ fn test_chain_scc_serialization_roundtrip() -> Cancellable<()> {
 fn test_chain_scc_serialization_multiple_steps() -> Cancellable<()> {
     test_chain_scc_serialization_multiple_steps_impl()
 }
+
+// ========== Tests for SkeletonScc ==========
+
+/// Test that a cancelled SkeletonScc step does not lose the processed universe, and that
+/// the interrupted state can be serialized and resumed.
+#[test]
+fn test_skeleton_scc_cancel_and_resume() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = SccConfig::from(&graph);
+    let expected =
+        SkeletonScc::configure(config.clone(), &graph).collect::<Cancellable<Vec<_>>>()?;
+
+    let stop = CancelAtomic::new();
+    stop.cancel();
+    let mut state = SkeletonState::from(&graph);
+    let mut found = Vec::new();
+    loop {
+        // Every step is first attempted in a cancelled context.
+        match cancel_this::on_atomic(stop.clone(), || SkeletonStep::step(&config, &mut state)) {
+            Err(Incomplete::Cancelled(_)) => {}
+            Ok(None) => break,
+            Ok(Some(_)) | Err(Incomplete::Suspended) => panic!("Expected cancellation."),
+            Err(_) => panic!("Unexpected step result."),
+        }
+
+        // Then the interrupted state is serialized, deserialized, and resumed.
+        let json = serde_json::to_string(&state).expect("Failed to serialize SkeletonState");
+        state = serde_json::from_str(&json).expect("Failed to deserialize SkeletonState");
+        match SkeletonStep::step(&config, &mut state) {
+            Ok(Some(scc)) => found.push(scc),
+            Ok(None) => break,
+            Err(Incomplete::Suspended) => {}
+            Err(Incomplete::Cancelled(_)) => panic!("Unexpected cancellation."),
+            Err(_) => panic!("Unexpected step result."),
+        }
+    }
+
+    assert_eq!(found, expected);
+    assert_eq!(found, vec![mk_states(&graph, ATTRACTOR_2)]);
+    Ok(())
+}
+
+#[test]
+fn test_scc_settings_roundtrip() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let mut config = SccConfig::new(graph.clone())
+        .deterministic(true)
+        .label("batch_job");
+    config.filter_long_lived = true;
+    config.should_trim = TrimSetting::Sinks;
+
+    let serialized = serde_json::to_string(&config.settings()).expect("Serialization failed");
+    assert!(!serialized.contains("graph"));
+    let settings: SccSettings = serde_json::from_str(&serialized).expect("Deserialization failed");
+    assert_eq!(settings, config.settings());
+
+    let config = settings.rehydrate(graph.clone());
+    assert!(config.deterministic && config.filter_long_lived);
+    assert_eq!(config.should_trim, TrimSetting::Sinks);
+    assert_eq!(config.label.as_deref(), Some("batch_job"));
+    let sccs = FwdBwdScc::configure(config, &graph).collect::<Cancellable<Vec<_>>>()?;
+    assert_eq!(sccs, vec![mk_states(&graph, ATTRACTOR_2)]);
+    Ok(())
+}
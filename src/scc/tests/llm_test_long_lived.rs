@@ -7,14 +7,18 @@
 //! A short-lived SCC has some variable where ALL states can transition outside the SCC
 //! by updating that variable.
 
-use crate::scc::retain_long_lived;
-use crate::scc::{FwdBwdScc, SccConfig};
-use crate::test_utils::llm_transition_builder::from_transitions;
-use crate::test_utils::mk_states;
+use crate::build::from_transitions;
+use crate::scc::{ChainScc, FwdBwdScc, SccConfig};
+use crate::scc::{retain_long_lived, retain_long_lived_k};
+use crate::test_utils::llm_example_network::create_parameterized_test_network;
+use crate::test_utils::{collect_state_numbers, mk_states};
 use crate::test_utils::{init_logger, symbolic_sets_to_sorted_sets};
+use crate::trapping::ForwardTrap;
+use crate::trimming::TrimSetting;
 use biodivine_lib_param_bn::BooleanNetwork;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
-use computation_process::Stateful;
+use computation_process::{Algorithm, Stateful};
 use std::collections::HashSet;
 
 /// Create a test network with two non-trivial SCCs:
@@ -120,38 +124,13 @@ fn test_with_long_lived_filter_reports_only_long_lived_scc() {
     );
 }
 
-/// Create a parameterized 2-variable network that tests the multicolor logic in `retain_long_lived`.
-///
-/// The network has one parameter `p` that switches between two behaviors:
-/// - When p=false (Network 1, SHORT-LIVED): A' = !A, B' = B
-/// - When p=true (Network 2, LONG-LIVED): A' = A ^ B, B' = A ^ B
-///
-/// For the test set S = {00, 11}:
-/// - Network 1 (p=false): ALL states can escape via A → short-lived; should be filtered
-/// - Network 2 (p=true): State 00 cannot escape via any variable → long-lived; should be kept
-fn create_parameterized_long_lived_network() -> SymbolicAsyncGraph {
-    // AEON model with parameter p:
-    // When p=false: A' = !A, B' = B (Network 1 - short-lived)
-    // When p=true: A' = A^B, B' = A^B (Network 2 - long-lived)
-    //
-    // We use observable edges (-?) to avoid monotonicity constraints.
-    let aeon_model = r#"
-        A -? A
-        B -? A
-        A -? B
-        B -? B
-        $A: (p & (A ^ B)) | (!p & !A)
-        $B: (p & (A ^ B)) | (!p & B)
-    "#;
-
-    let bn = BooleanNetwork::try_from(aeon_model).expect("Failed to parse AEON model");
-    SymbolicAsyncGraph::new(&bn).expect("Failed to create graph")
-}
-
 /// Test that `retain_long_lived` correctly filters colors using intersection logic.
 ///
 /// This test directly calls `retain_long_lived` with a multicolor set and verifies
-/// that only the long-lived color is retained.
+/// that only the long-lived color is retained. For the test set S = {00, 11} in the
+/// parametrized test network:
+/// - Network 1 (p=false): ALL states can escape via A → short-lived; should be filtered
+/// - Network 2 (p=true): State 00 cannot escape via any variable → long-lived; should be kept
 ///
 /// - Start: safe_colors = {p=true, p=false}
 /// - After checking A: safe_colors = {p=true} (Network 1 has all states escaping via A)
@@ -159,7 +138,7 @@ fn create_parameterized_long_lived_network() -> SymbolicAsyncGraph {
 #[test]
 fn test_retain_long_lived_multi_color_uses_intersection() {
     init_logger();
-    let graph = create_parameterized_long_lived_network();
+    let graph = create_parameterized_test_network();
 
     // Verify we have 2 colors (p=true and p=false)
     let all_colors = graph.mk_unit_colored_vertices().colors();
@@ -211,3 +190,107 @@ fn test_retain_long_lived_multi_color_uses_intersection() {
         "The retained states should still be {{00, 11}}"
     );
 }
+
+/// Test that short-lived remainders are discarded without changing the result, also
+/// when trimming is disabled (i.e., all trivial states have to be processed).
+#[test]
+fn test_long_lived_filter_without_trimming() {
+    init_logger();
+    let graph = create_long_lived_test_network();
+
+    let mut config = SccConfig::new(graph.clone());
+    config.filter_long_lived = true;
+    config.should_trim = TrimSetting::None;
+
+    let fwd_bwd = FwdBwdScc::configure(config.clone(), &graph)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    let chain = ChainScc::configure(config, &graph)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+
+    let expected: HashSet<u32> = [0b000, 0b100].iter().copied().collect();
+    assert_eq!(
+        symbolic_sets_to_sorted_sets(&graph, &fwd_bwd, 3),
+        vec![expected.clone()]
+    );
+    assert_eq!(
+        symbolic_sets_to_sorted_sets(&graph, &chain, 3),
+        vec![expected]
+    );
+}
+
+/// Test that pinning a color produces the same SCCs as the network with a fixed parameter.
+#[test]
+fn test_pin_color_matches_concrete_network() {
+    init_logger();
+    let graph = create_parameterized_test_network();
+    let sccs_of = |config: SccConfig| {
+        let graph = config.graph.clone();
+        let mut sccs = FwdBwdScc::configure(config, &graph)
+            .map(|it| collect_state_numbers(&graph, &it.unwrap(), 2))
+            .collect::<Vec<_>>();
+        sccs.sort();
+        sccs
+    };
+
+    let mut pinned = Vec::new();
+    let mut remaining = graph.mk_unit_colors();
+    while !remaining.is_empty() {
+        let color = remaining.pick_singleton();
+        remaining = remaining.minus(&color);
+        pinned.push(sccs_of(SccConfig::new(graph.clone()).pin_color(&color)));
+    }
+    pinned.sort();
+
+    let mut concrete = Vec::new();
+    for functions in ["$A: !A\n$B: B", "$A: A ^ B\n$B: A ^ B"] {
+        let aeon = format!("A -?? A\nB -?? A\nA -?? B\nB -?? B\n{functions}");
+        let bn = BooleanNetwork::try_from(aeon.as_str()).expect("Failed to parse AEON model");
+        let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+        concrete.push(sccs_of(SccConfig::new(graph)));
+    }
+    concrete.sort();
+
+    // p=false: {00, 10} and {01, 11}; p=true: {01, 10, 11}.
+    assert_eq!(
+        concrete,
+        vec![vec![vec![0, 2], vec![1, 3]], vec![vec![1, 2, 3]]]
+    );
+    assert_eq!(pinned, concrete);
+}
+
+/// Test that `retain_long_lived_k` interpolates between the long-lived check and the greatest
+/// forward trap.
+#[test]
+fn test_retain_long_lived_k() {
+    init_logger();
+    let graph = create_long_lived_test_network();
+
+    // No variable can be used to escape from all states of the set, so the set is long-lived,
+    // but 011 and 111 can escape in one step.
+    let set = mk_states(&graph, &[0b000, 0b100, 0b011, 0b111]);
+    assert_eq!(retain_long_lived(&graph, &set), set);
+    assert_eq!(retain_long_lived_k(&graph, &set, Some(1)).unwrap(), set);
+
+    let expected = mk_states(&graph, &[0b000, 0b100]);
+    for k in [Some(2), Some(10), None] {
+        assert_eq!(retain_long_lived_k(&graph, &set, k).unwrap(), expected);
+    }
+    assert_eq!(ForwardTrap::run(&graph, set.clone()).unwrap(), expected);
+
+    // The short-lived SCC alone is removed already by the first round.
+    let short_lived = mk_states(&graph, &[0b011, 0b111]);
+    assert!(
+        retain_long_lived_k(&graph, &short_lived, None)
+            .unwrap()
+            .is_empty()
+    );
+
+    // With zero rounds, nothing is removed.
+    assert_eq!(retain_long_lived_k(&graph, &set, Some(0)).unwrap(), set);
+    assert_eq!(
+        retain_long_lived_k(&graph, &short_lived, Some(0)).unwrap(),
+        short_lived
+    );
+}
@@ -1,9 +1,9 @@
-//! Tests comparing FwdBwdScc vs. ChainScc on real model files.
+//! Tests comparing FwdBwdScc vs. ChainScc (and SkeletonScc) on real model files.
 //!
 //! These tests verify that both algorithms produce the same results,
 //! while also testing with timeouts to ensure tests don't hang.
 
-use crate::scc::{ChainScc, FwdBwdScc};
+use crate::scc::{ChainScc, FwdBwdScc, SccConfig, SkeletonScc};
 use crate::test_utils::symbolic_sets_to_sorted_sets;
 use biodivine_lib_param_bn::BooleanNetwork;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
@@ -42,7 +42,8 @@ fn compare_scc_results(
     }
 }
 
-/// Generic helper function to compare FwdBwdScc and ChainScc algorithms.
+/// Generic helper function to compare FwdBwdScc with ChainScc and SkeletonScc algorithms
+/// (and with the reversed variants of FwdBwdScc and ChainScc).
 fn test_scc_comparison_impl(model_path: &str) -> Cancellable<()> {
     // Load the model
     let bn = BooleanNetwork::try_from_file(model_path)
@@ -66,8 +67,49 @@ fn test_scc_comparison_impl(model_path: &str) -> Cancellable<()> {
         .computation::<Vec<_>>()
         .compute()?;
 
+    // Collect SCCs from SkeletonScc
+    let skeleton_sccs = SkeletonScc::configure(graph.clone(), &graph)
+        .computation::<Vec<_>>()
+        .compute()?;
+
+    // Collect SCCs from both algorithms on the reversed graph
+    let reversed = SccConfig::new(graph.clone()).reversed(true);
+    let fwd_bwd_reversed_sccs = FwdBwdScc::configure(reversed.clone(), &graph)
+        .computation::<Vec<_>>()
+        .compute()?;
+    let chain_reversed_sccs = ChainScc::configure(reversed, &graph)
+        .computation::<Vec<_>>()
+        .compute()?;
+
     // Compare results
-    compare_scc_results(&graph, fwd_bwd_sccs, chain_sccs, bn.num_vars(), model_path);
+    compare_scc_results(
+        &graph,
+        fwd_bwd_sccs.clone(),
+        fwd_bwd_reversed_sccs,
+        bn.num_vars(),
+        model_path,
+    );
+    compare_scc_results(
+        &graph,
+        fwd_bwd_sccs.clone(),
+        chain_reversed_sccs,
+        bn.num_vars(),
+        model_path,
+    );
+    compare_scc_results(
+        &graph,
+        fwd_bwd_sccs.clone(),
+        chain_sccs,
+        bn.num_vars(),
+        model_path,
+    );
+    compare_scc_results(
+        &graph,
+        fwd_bwd_sccs,
+        skeleton_sccs,
+        bn.num_vars(),
+        model_path,
+    );
 
     Ok(())
 }
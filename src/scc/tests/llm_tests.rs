@@ -6,16 +6,31 @@
 //! The tests are generic and can be used to test any algorithm that implements
 //! the `SccAlgorithm` trait.
 
+use crate::attractor::{XieBeerelAttractors, XieBeerelState};
+use crate::build::from_transitions;
 use crate::reachability::{
     BackwardReachability, BackwardReachabilityBfs, ForwardReachability, ForwardReachabilityBfs,
 };
-use crate::scc::{ChainScc, ChainState, FwdBwdScc, FwdBwdSccBfs, FwdBwdState, SccAlgorithm};
-use crate::test_utils::llm_example_network::create_test_network;
+use crate::scc::{
+    ChainScc, ChainSccWithProgress, ChainState, ChainStep, CoreReduction, FwdBwdScc, FwdBwdSccBfs,
+    FwdBwdSccBfsWithProgress, FwdBwdSccWithProgress, FwdBwdState, FwdBwdStep, SccAlgorithm,
+    SccConfig, SccItem, SccSizeHistogram, SkeletonScc, SkeletonSccWithProgress, SkeletonState,
+    SkeletonStep, colors_with_nontrivial_scc, has_nontrivial_scc, is_scc, nontrivial_colors,
+    scc_colors, sccs_in_region,
+};
 use crate::test_utils::llm_example_network::sets::ATTRACTOR_2;
-use crate::test_utils::llm_transition_builder::from_transitions;
-use crate::test_utils::{init_logger, mk_states, symbolic_sets_to_sorted_sets};
+use crate::test_utils::llm_example_network::{
+    create_parameterized_test_network, create_test_network,
+};
+use crate::test_utils::{
+    collect_state_numbers, init_logger, mk_states, symbolic_sets_to_sorted_sets,
+};
+use crate::trimming::TrimSetting;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use std::collections::HashSet;
+use computation_process::{Computable, GenAlgorithm, GeneratorStep, Incomplete, Stateful};
+use num_bigint::BigUint;
+use std::collections::{BTreeMap, HashSet};
 
 /// Verify that the SCCs found match the expected SCCs exactly.
 /// This handles the fact that SCCs can be returned in arbitrary order.
@@ -60,6 +75,44 @@ fn verify_sccs(
 
 // ========== Parametrized test helpers ==========
 
+/// Generic helper function for testing that deterministic pivots produce a stable order.
+fn test_deterministic_order_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: for<'a> From<&'a SymbolicAsyncGraph>,
+{
+    init_logger();
+    // Three disjoint 2-cycles: 000 ↔ 001, 010 ↔ 011, 110 ↔ 111
+    let transitions = vec![
+        (0b000, 0b001),
+        (0b001, 0b000),
+        (0b010, 0b011),
+        (0b011, 0b010),
+        (0b110, 0b111),
+        (0b111, 0b110),
+    ];
+
+    let run = || {
+        let bn = from_transitions(3, &transitions).expect("Failed to create network");
+        let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+        let config = SccConfig::new(graph.clone()).deterministic(true);
+        ALG::configure(config, &graph)
+            .map(|it| collect_state_numbers(&graph, &it.unwrap(), 3))
+            .collect::<Vec<_>>()
+    };
+
+    let first = run();
+    assert_eq!(first, run());
+    // The first pivot is the smallest state.
+    assert_eq!(first[0], vec![0b000, 0b001]);
+    let mut sorted = first.clone();
+    sorted.sort();
+    assert_eq!(
+        sorted,
+        vec![vec![0b000, 0b001], vec![0b010, 0b011], vec![0b110, 0b111]]
+    );
+}
+
 /// Generic helper function for testing single 2-cycle detection.
 fn test_single_2_cycle_impl<STATE, ALG>()
 where
@@ -576,3 +629,947 @@ fn test_llm_example_network_chain() {
 fn test_complex_network_chain() {
     test_complex_network_impl::<ChainState, ChainScc>()
 }
+
+#[test]
+fn test_deterministic_order_fwd_bwd() {
+    test_deterministic_order_impl::<
+        FwdBwdState<ForwardReachability, BackwardReachability>,
+        FwdBwdScc,
+    >();
+}
+
+#[test]
+fn test_deterministic_order_chain() {
+    test_deterministic_order_impl::<ChainState, ChainScc>();
+}
+
+/// Generic helper function for testing that `auto_trap` restricts a non-trap universe.
+fn test_auto_trap_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: From<GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+    // 100 can escape to 000, which is not in the initial set.
+    let initial = mk_states(&graph, &[0b100, 0b110, 0b111]);
+    let config = SccConfig::new(graph.clone()).auto_trap(true);
+    let found_sccs = ALG::configure(config, initial)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+}
+
+#[test]
+fn test_auto_trap_fwd_bwd() {
+    test_auto_trap_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_auto_trap_chain() {
+    test_auto_trap_impl::<ChainState, ChainScc>();
+}
+
+/// Generic helper function for testing that `reachable_from` restricts the search
+/// to the states reachable from a seed.
+fn test_reachable_from_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: From<GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+
+    // 101 -> 111 <-> 110: the only non-trivial SCC is reachable.
+    let seed = mk_states(&graph, &[0b101]);
+    let config = SccConfig::new(graph.clone()).reachable_from(seed);
+    let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+
+    // 001 -> 000: no non-trivial SCC is reachable.
+    let seed = mk_states(&graph, &[0b001]);
+    let config = SccConfig::new(graph.clone()).reachable_from(seed);
+    let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[], 3);
+
+    // With x1 frozen, 100 only reaches 000 (the path to 110 <-> 111 needs x1). The region
+    // must respect the active variables regardless of the order of the builder calls.
+    let x: Vec<_> = graph.variables().collect();
+    let seed = mk_states(&graph, &[0b100]);
+    let configs = [
+        SccConfig::new(graph.clone())
+            .reachable_from(seed.clone())
+            .with_active_variables([x[0], x[2]]),
+        SccConfig::new(graph.clone())
+            .with_active_variables([x[0], x[2]])
+            .reachable_from(seed),
+    ];
+    for config in configs {
+        let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+            .map(|it| it.unwrap())
+            .collect::<Vec<_>>();
+        verify_sccs(&graph, found_sccs, &[], 3);
+    }
+}
+
+#[test]
+fn test_reachable_from_fwd_bwd() {
+    test_reachable_from_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_reachable_from_chain() {
+    test_reachable_from_impl::<ChainState, ChainScc>();
+}
+
+#[test]
+fn test_fwd_bwd_intermediate_sets() {
+    init_logger();
+    let graph = create_test_network();
+    let mut config = SccConfig::new(graph.clone());
+    config.should_trim = TrimSetting::None;
+    let mut state = FwdBwdState::<ForwardReachability, BackwardReachability>::from(&graph);
+    assert!(state.current_pivot().is_none());
+
+    let mut seen_backward = false;
+    let mut seen_forward = false;
+    loop {
+        match FwdBwdStep::step(&config, &mut state) {
+            Ok(None) => break,
+            Ok(Some(_)) | Err(Incomplete::Suspended) => {}
+            Err(_) => panic!("Unexpected cancellation."),
+        }
+        if let Some(backward) = state.current_backward() {
+            let pivot = state.current_pivot().unwrap();
+            assert_eq!(pivot.vertices().exact_cardinality(), BigUint::from(1u32));
+            assert!(pivot.is_subset(backward));
+            seen_backward = true;
+        }
+        if let Some(forward) = state.current_forward() {
+            let pivot = state.current_pivot().unwrap();
+            assert!(pivot.is_subset(forward));
+            assert!(state.current_backward().is_some());
+            seen_forward = true;
+        }
+    }
+    assert!(seen_backward && seen_forward);
+    assert!(state.current_pivot().is_none());
+    assert!(state.current_backward().is_none());
+    assert!(state.current_forward().is_none());
+}
+
+/// Generic helper function for testing SCC detection with a subset of active variables.
+fn test_active_variables_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: for<'a> From<&'a SymbolicAsyncGraph>,
+{
+    init_logger();
+    // Same network as in `test_complex_network_impl`: SCC {0000, 1000} only needs x0,
+    // while SCC {0001, 0011, 1001, 1011} needs both x0 and x2.
+    let transitions = vec![
+        (0b0000, 0b1000),
+        (0b1000, 0b0000),
+        (0b0001, 0b1001),
+        (0b1001, 0b1011),
+        (0b1011, 0b0011),
+        (0b0011, 0b0001),
+    ];
+    let bn = from_transitions(4, &transitions).expect("Failed to create network");
+    let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+    let x: Vec<_> = graph.variables().collect();
+
+    let run = |config: SccConfig| {
+        ALG::configure(config, &graph)
+            .map(|it| it.unwrap())
+            .collect::<Vec<_>>()
+    };
+
+    let config = SccConfig::new(graph.clone()).with_active_variables([x[0], x[2]]);
+    verify_sccs(
+        &graph,
+        run(config),
+        &[&[0b0000, 0b1000], &[0b0001, 0b0011, 0b1001, 0b1011]],
+        4,
+    );
+
+    // With x2 frozen, the 4-cycle is broken.
+    let config = SccConfig::new(graph.clone()).with_active_variables([x[0]]);
+    verify_sccs(&graph, run(config), &[&[0b0000, 0b1000]], 4);
+
+    // With x0 frozen, no cycle remains.
+    let config = SccConfig::new(graph.clone()).with_active_variables([x[2]]);
+    verify_sccs(&graph, run(config), &[], 4);
+
+    // With no active variables, every state is a trivial SCC.
+    let config = SccConfig::new(graph.clone()).with_active_variables([]);
+    verify_sccs(&graph, run(config), &[], 4);
+}
+
+#[test]
+fn test_active_variables_fwd_bwd() {
+    test_active_variables_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>(
+    );
+}
+
+#[test]
+fn test_active_variables_chain() {
+    test_active_variables_impl::<ChainState, ChainScc>();
+}
+
+/// Generic helper function for testing that `assume_trapped` does not change the result
+/// on a trapped universe.
+fn test_assume_trapped_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: From<GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+    for trim in [TrimSetting::Both, TrimSetting::Sources, TrimSetting::Sinks] {
+        let mut config = SccConfig::new(graph.clone())
+            .auto_trap(true)
+            .assume_trapped(true);
+        config.should_trim = trim;
+        let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+            .map(|it| it.unwrap())
+            .collect::<Vec<_>>();
+        verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+    }
+}
+
+#[test]
+fn test_assume_trapped_fwd_bwd() {
+    test_assume_trapped_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_assume_trapped_chain() {
+    test_assume_trapped_impl::<ChainState, ChainScc>();
+}
+
+#[test]
+fn test_initial_pivot_hint_chain() {
+    init_logger();
+    let graph = create_test_network();
+    let mut config = SccConfig::new(graph.clone()).deterministic(true);
+    config.should_trim = TrimSetting::None;
+
+    // With the hint inside the attractor, it is the first extracted component.
+    let hinted = config
+        .clone()
+        .initial_pivot_hint(mk_states(&graph, &[0b110]));
+    let mut generator = ChainScc::configure(hinted, &graph);
+    let first = generator.next().unwrap().unwrap();
+    assert_eq!(
+        collect_state_numbers(&graph, &first, 3),
+        ATTRACTOR_2.to_vec()
+    );
+    assert!(generator.next().is_none());
+
+    // Empty hints and hints outside the universe are ignored.
+    for hint in [&[][..], &[0b000][..]] {
+        let hinted = config.clone().initial_pivot_hint(mk_states(&graph, hint));
+        let universe = mk_states(&graph, &[0b100, 0b110, 0b111]);
+        let found_sccs = ChainScc::configure(hinted, universe)
+            .map(|it| it.unwrap())
+            .collect::<Vec<_>>();
+        verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+    }
+}
+
+/// Run the SCC algorithm step by step, returning the reported SCCs and the number of steps.
+fn run_steps<STATE, STEP>(
+    config: &SccConfig,
+    mut state: STATE,
+) -> (Vec<GraphColoredVertices>, usize)
+where
+    STEP: GeneratorStep<SccConfig, STATE, GraphColoredVertices>,
+{
+    let mut sccs = Vec::new();
+    let mut steps = 0;
+    loop {
+        steps += 1;
+        match STEP::step(config, &mut state) {
+            Ok(None) => return (sccs, steps),
+            Ok(Some(scc)) => sccs.push(scc),
+            Err(Incomplete::Suspended) => {}
+            Err(_) => panic!("Unexpected cancellation."),
+        }
+    }
+}
+
+/// Generic helper function for testing that an empty universe is exhausted immediately
+/// and a single state universe reports no SCC.
+fn test_degenerate_universe_impl<STATE, STEP>()
+where
+    STATE: From<GraphColoredVertices>,
+    STEP: GeneratorStep<SccConfig, STATE, GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+    for config in [
+        SccConfig::new(graph.clone()),
+        SccConfig::new(graph.clone()).should_trim(TrimSetting::None),
+    ] {
+        let empty = graph.mk_empty_colored_vertices();
+        let (sccs, steps) = run_steps::<STATE, STEP>(&config, STATE::from(empty));
+        assert!(sccs.is_empty());
+        assert_eq!(steps, 1);
+
+        for state in [0b000, 0b011, 0b110] {
+            let single = mk_states(&graph, &[state]);
+            let (sccs, _) = run_steps::<STATE, STEP>(&config, STATE::from(single));
+            assert!(sccs.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_degenerate_universe_fwd_bwd() {
+    test_degenerate_universe_impl::<
+        FwdBwdState<ForwardReachability, BackwardReachability>,
+        FwdBwdStep<ForwardReachability, BackwardReachability>,
+    >();
+}
+
+#[test]
+fn test_degenerate_universe_chain() {
+    test_degenerate_universe_impl::<ChainState, ChainStep>();
+}
+
+#[test]
+fn test_degenerate_universe_skeleton() {
+    test_degenerate_universe_impl::<SkeletonState, SkeletonStep>();
+}
+
+/// Generic helper function for testing that the trimming setting does not change
+/// the reported SCCs.
+fn test_trim_settings_impl<STATE, ALG>()
+where
+    STATE: for<'a> From<&'a SymbolicAsyncGraph>,
+    ALG: SccAlgorithm<STATE>,
+{
+    init_logger();
+    let graph = create_test_network();
+    for setting in [
+        TrimSetting::Both,
+        TrimSetting::Sources,
+        TrimSetting::Sinks,
+        TrimSetting::None,
+    ] {
+        let config = SccConfig::new(graph.clone()).should_trim(setting);
+        let found_sccs = ALG::configure(config, &graph)
+            .map(|it| it.unwrap())
+            .collect::<Vec<_>>();
+        verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+    }
+}
+
+#[test]
+fn test_trim_settings_fwd_bwd() {
+    test_trim_settings_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_trim_settings_chain() {
+    test_trim_settings_impl::<ChainState, ChainScc>();
+}
+
+/// Generic helper function for testing that the remaining cardinality reported by the
+/// algorithm state never increases and reaches zero once the computation is finished.
+fn test_remaining_cardinality_impl<STATE, STEP>(remaining: fn(&STATE) -> BigUint)
+where
+    STATE: for<'a> From<&'a SymbolicAsyncGraph>,
+    STEP: GeneratorStep<SccConfig, STATE, GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+    let config = SccConfig::new(graph.clone());
+    let mut state = STATE::from(&graph);
+    let mut last = remaining(&state);
+    assert_eq!(last, BigUint::from(8u32));
+    loop {
+        match STEP::step(&config, &mut state) {
+            Ok(None) => break,
+            Ok(Some(_)) | Err(Incomplete::Suspended) => {}
+            Err(_) => panic!("Unexpected cancellation."),
+        }
+        let current = remaining(&state);
+        assert!(current <= last);
+        last = current;
+    }
+    assert_eq!(last, BigUint::from(0u32));
+}
+
+#[test]
+fn test_remaining_cardinality_fwd_bwd() {
+    test_remaining_cardinality_impl::<
+        FwdBwdState<ForwardReachability, BackwardReachability>,
+        FwdBwdStep<ForwardReachability, BackwardReachability>,
+    >(FwdBwdState::remaining_cardinality);
+}
+
+#[test]
+fn test_remaining_cardinality_chain() {
+    test_remaining_cardinality_impl::<ChainState, ChainStep>(ChainState::remaining_cardinality);
+}
+
+#[test]
+fn test_remaining_cardinality_skeleton() {
+    test_remaining_cardinality_impl::<SkeletonState, SkeletonStep>(
+        SkeletonState::remaining_cardinality,
+    );
+}
+
+/// Generic helper function for testing that the remaining universe shrinks during the
+/// decomposition, is consistent with the remaining cardinality, and is `None` at the end.
+fn test_remaining_universe_impl<STATE, STEP>(
+    remaining: fn(&STATE) -> Option<GraphColoredVertices>,
+    cardinality: fn(&STATE) -> BigUint,
+) where
+    STATE: for<'a> From<&'a SymbolicAsyncGraph>,
+    STEP: GeneratorStep<SccConfig, STATE, GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+    let config = SccConfig::new(graph.clone());
+    let mut state = STATE::from(&graph);
+    let mut last = graph.mk_unit_colored_vertices();
+    assert_eq!(remaining(&state), Some(last.clone()));
+    loop {
+        match STEP::step(&config, &mut state) {
+            Ok(None) => break,
+            Ok(Some(_)) | Err(Incomplete::Suspended) => {}
+            Err(_) => panic!("Unexpected cancellation."),
+        }
+        let current = remaining(&state).unwrap_or_else(|| graph.mk_empty_colored_vertices());
+        assert!(current.is_subset(&last));
+        // The pending universes are disjoint.
+        assert_eq!(current.exact_cardinality(), cardinality(&state));
+        last = current;
+    }
+    assert!(remaining(&state).is_none());
+}
+
+#[test]
+fn test_remaining_universe_fwd_bwd() {
+    test_remaining_universe_impl::<
+        FwdBwdState<ForwardReachability, BackwardReachability>,
+        FwdBwdStep<ForwardReachability, BackwardReachability>,
+    >(
+        FwdBwdState::remaining_universe,
+        FwdBwdState::remaining_cardinality,
+    );
+}
+
+#[test]
+fn test_remaining_universe_chain() {
+    test_remaining_universe_impl::<ChainState, ChainStep>(
+        ChainState::remaining_universe,
+        ChainState::remaining_cardinality,
+    );
+}
+
+#[test]
+fn test_remaining_universe_skeleton() {
+    test_remaining_universe_impl::<SkeletonState, SkeletonStep>(
+        SkeletonState::remaining_universe,
+        SkeletonState::remaining_cardinality,
+    );
+}
+
+/// Generic helper function for testing that terminal-only SCCs are exactly the non-trivial
+/// attractors found by Xie-Beerel.
+fn test_terminal_only_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: From<GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+    let config = SccConfig::new(graph.clone()).terminal_only(true);
+    let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+
+    // Two cycles connected by a transition: 00 <-> 01 -> 11 <-> 10, and a fixed point
+    // cannot be reported, since it is trivial.
+    let transitions = [
+        (0b000, 0b001),
+        (0b001, 0b000),
+        (0b001, 0b011),
+        (0b011, 0b010),
+        (0b010, 0b011),
+        (0b100, 0b101),
+        (0b101, 0b111),
+        (0b111, 0b110),
+    ];
+    let bn = from_transitions(3, &transitions).unwrap();
+    let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+    let attractors = XieBeerelAttractors::configure(&graph, XieBeerelState::from(&graph))
+        .map(|it| it.unwrap())
+        .filter(|it| it.exact_cardinality() > BigUint::from(1u32))
+        .collect::<Vec<_>>();
+    let config = SccConfig::new(graph.clone()).terminal_only(true);
+    let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        symbolic_sets_to_sorted_sets(&graph, &found_sccs, 3),
+        symbolic_sets_to_sorted_sets(&graph, &attractors, 3)
+    );
+    verify_sccs(&graph, found_sccs, &[&[0b010, 0b011]], 3);
+}
+
+#[test]
+fn test_terminal_only_fwd_bwd() {
+    test_terminal_only_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_terminal_only_chain() {
+    test_terminal_only_impl::<ChainState, ChainScc>();
+}
+
+#[test]
+fn test_terminal_only_skeleton() {
+    test_terminal_only_impl::<SkeletonState, SkeletonScc>();
+}
+
+/// Generic helper function for testing that reversing the graph exploration does not
+/// change the reported SCCs.
+fn test_reversed_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: From<GraphColoredVertices>,
+{
+    init_logger();
+    let graph = create_test_network();
+    for trim in [TrimSetting::None, TrimSetting::Both] {
+        let mut config = SccConfig::new(graph.clone()).reversed(true);
+        config.should_trim = trim;
+        let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+            .map(|it| it.unwrap())
+            .collect::<Vec<_>>();
+        verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+    }
+
+    // Two cycles connected by a transition: 00 <-> 01 -> 11 <-> 10.
+    let bn = from_transitions(2, &[(0, 1), (1, 0), (1, 3), (3, 2), (2, 3)]).unwrap();
+    let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+    let mut config = SccConfig::new(graph.clone()).reversed(true);
+    config.should_trim = TrimSetting::None;
+    let found_sccs = ALG::configure(config, graph.mk_unit_colored_vertices())
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[&[0b00, 0b01], &[0b10, 0b11]], 2);
+}
+
+#[test]
+fn test_reversed_fwd_bwd() {
+    test_reversed_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_reversed_chain() {
+    test_reversed_impl::<ChainState, ChainScc>();
+}
+
+#[test]
+fn test_single_2_cycle_skeleton() {
+    test_single_2_cycle_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_single_3_cycle_skeleton() {
+    test_single_3_cycle_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_two_disjoint_2_cycles_skeleton() {
+    test_two_disjoint_2_cycles_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_multiple_sccs_different_sizes_skeleton() {
+    test_multiple_sccs_different_sizes_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_scc_with_branching_skeleton() {
+    test_scc_with_branching_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_only_trivial_sccs_skeleton() {
+    test_only_trivial_sccs_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_4_cycle_skeleton() {
+    test_4_cycle_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_scc_with_multiple_paths_skeleton() {
+    test_scc_with_multiple_paths_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_llm_example_network_skeleton() {
+    test_llm_example_network_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_complex_network_skeleton() {
+    test_complex_network_impl::<SkeletonState, SkeletonScc>()
+}
+
+#[test]
+fn test_deterministic_order_skeleton() {
+    test_deterministic_order_impl::<SkeletonState, SkeletonScc>();
+}
+
+#[test]
+fn test_auto_trap_skeleton() {
+    test_auto_trap_impl::<SkeletonState, SkeletonScc>();
+}
+
+#[test]
+fn test_reachable_from_skeleton() {
+    test_reachable_from_impl::<SkeletonState, SkeletonScc>();
+}
+
+#[test]
+fn test_active_variables_skeleton() {
+    test_active_variables_impl::<SkeletonState, SkeletonScc>();
+}
+
+/// Generic helper function for testing that verified SCC detection succeeds.
+fn test_verify_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: for<'a> From<&'a SymbolicAsyncGraph>,
+{
+    init_logger();
+    let graph = create_test_network();
+    let config = SccConfig::new(graph.clone()).verify(true);
+    let found_sccs = ALG::configure(config, &graph)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[ATTRACTOR_2], 3);
+}
+
+#[test]
+fn test_verify_fwd_bwd() {
+    test_verify_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_verify_chain() {
+    test_verify_impl::<ChainState, ChainScc>();
+}
+
+#[test]
+fn test_verify_skeleton() {
+    test_verify_impl::<SkeletonState, SkeletonScc>();
+}
+
+#[test]
+#[should_panic(expected = "SCC verification failed")]
+fn test_verify_detects_invalid_scc() {
+    init_logger();
+    let graph = create_test_network();
+    let config = SccConfig::new(graph.clone()).verify(true);
+    // Not an SCC: 100 can reach 110, but not vice versa.
+    let invalid = mk_states(&graph, &[0b110, 0b100]);
+    crate::scc::verify_scc(&config, &invalid, &graph.mk_unit_colored_vertices());
+}
+
+/// Generic helper function for testing verification of SCCs found in a non-closed initial set.
+fn test_verify_subset_impl<STATE, ALG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    STATE: From<GraphColoredVertices>,
+{
+    init_logger();
+    // A 4-cycle 00 → 01 → 11 → 10 → 00 with an extra edge 01 → 00. The initial set
+    // {00, 01, 11} cuts the cycle, so only {00, 01} is an SCC within this set, even though
+    // the whole state space is one SCC of the full graph.
+    let transitions = vec![
+        (0b00, 0b01), // x0 flips
+        (0b01, 0b11), // x1 flips
+        (0b11, 0b10), // x0 flips
+        (0b10, 0b00), // x1 flips
+        (0b01, 0b00), // x0 flips
+    ];
+
+    let bn = from_transitions(2, &transitions).expect("Failed to create network");
+    let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+    let subset = mk_states(&graph, &[0b00, 0b01, 0b11]);
+
+    let config = SccConfig::new(graph.clone()).verify(true);
+    let found_sccs = ALG::configure(config, subset)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[&[0b00, 0b01]], 2);
+}
+
+#[test]
+fn test_verify_subset_fwd_bwd() {
+    test_verify_subset_impl::<FwdBwdState<ForwardReachability, BackwardReachability>, FwdBwdScc>();
+}
+
+#[test]
+fn test_verify_subset_chain() {
+    test_verify_subset_impl::<ChainState, ChainScc>();
+}
+
+#[test]
+fn test_verify_subset_skeleton() {
+    test_verify_subset_impl::<SkeletonState, SkeletonScc>();
+}
+
+#[test]
+fn test_has_nontrivial_scc() {
+    init_logger();
+    let graph = create_test_network();
+    assert!(has_nontrivial_scc(&graph).unwrap());
+    assert_eq!(
+        colors_with_nontrivial_scc(&graph).unwrap(),
+        graph.mk_unit_colors()
+    );
+
+    let bn = from_transitions(3, &[(0b001, 0b000)]).expect("Failed to create network");
+    let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+    assert!(!has_nontrivial_scc(&graph).unwrap());
+    assert!(colors_with_nontrivial_scc(&graph).unwrap().is_empty());
+}
+
+#[test]
+fn test_core_reduction() {
+    init_logger();
+    let graph = create_test_network();
+    let all = graph.mk_unit_colored_vertices();
+
+    // Full settings: trimming removes everything but the cyclic attractor.
+    let mut reduction = CoreReduction::new(&graph, all.clone())
+        .should_trim(TrimSetting::Both)
+        .filter_long_lived(true);
+    let core = reduction.compute().unwrap();
+    assert_eq!(collect_state_numbers(&graph, &core, 3), vec![0b110, 0b111]);
+
+    // The same result when inheriting the settings of an SCC config.
+    let mut config = SccConfig::new(graph.clone());
+    config.filter_long_lived = true;
+    assert_eq!(config.core_reduction(all.clone()).compute().unwrap(), core);
+
+    // Without any reduction, the set is unchanged.
+    let mut reduction = CoreReduction::new(&graph, all.clone()).should_trim(TrimSetting::None);
+    assert_eq!(reduction.compute().unwrap(), all);
+
+    // A single state which can escape is removed by long-lived filtering.
+    let escaping = mk_states(&graph, &[0b001]);
+    let mut reduction = CoreReduction::new(&graph, escaping)
+        .should_trim(TrimSetting::None)
+        .filter_long_lived(true);
+    assert!(reduction.compute().unwrap().is_empty());
+}
+
+#[test]
+fn test_peak_symbolic_size() {
+    init_logger();
+    let graph = create_test_network();
+    let config = SccConfig::new(graph.clone()).track_peak_symbolic_size();
+    let found_sccs = ChainScc::configure(config.clone(), &graph)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(found_sccs.len(), 1);
+    assert!(config.peak_symbolic_size() >= found_sccs[0].symbolic_size());
+    assert_eq!(SccConfig::new(graph).peak_symbolic_size(), 0);
+}
+
+#[test]
+fn test_is_scc() {
+    init_logger();
+    let graph = create_test_network();
+    assert!(is_scc(&graph, &mk_states(&graph, ATTRACTOR_2)).unwrap());
+    assert!(is_scc(&graph, &mk_states(&graph, &[0b000])).unwrap());
+    assert!(is_scc(&graph, &mk_states(&graph, &[0b011])).unwrap());
+    // 011 -> 111, but 111 cannot return to 011.
+    assert!(!is_scc(&graph, &mk_states(&graph, &[0b011, 0b111])).unwrap());
+    // 110 and 111 are not connected to 000.
+    assert!(!is_scc(&graph, &mk_states(&graph, &[0b000, 0b110, 0b111])).unwrap());
+    assert!(!is_scc(&graph, &graph.mk_empty_colored_vertices()).unwrap());
+
+    let graph = create_parameterized_test_network();
+    // 01 <-> 11 for both colors (for p=true, this cycle is a part of the larger SCC {01, 10, 11}).
+    let set = mk_states(&graph, &[0b01, 0b11]);
+    assert_eq!(scc_colors(&graph, &set).unwrap(), graph.mk_unit_colors());
+    assert!(is_scc(&graph, &set).unwrap());
+    // 00 <-> 10 only for p=false (for p=true, 00 is a fixed point).
+    let set = mk_states(&graph, &[0b00, 0b10]);
+    assert_eq!(
+        scc_colors(&graph, &set).unwrap().exact_cardinality(),
+        BigUint::from(1u32)
+    );
+    assert!(!is_scc(&graph, &set).unwrap());
+}
+
+#[test]
+fn test_scc_size_histogram() {
+    init_logger();
+    let graph = create_test_network();
+    let histogram = SccSizeHistogram::configure(&graph, &graph)
+        .compute()
+        .unwrap();
+    assert_eq!(histogram, BTreeMap::from([(BigUint::from(2u32), 1)]));
+
+    // For p=false, the SCCs are {00, 10} and {01, 11}; for p=true, the only non-trivial
+    // SCC is {01, 10, 11}.
+    let graph = create_parameterized_test_network();
+    let histogram = SccSizeHistogram::configure(&graph, &graph)
+        .compute()
+        .unwrap();
+    assert_eq!(
+        histogram,
+        BTreeMap::from([(BigUint::from(2u32), 2), (BigUint::from(3u32), 1)])
+    );
+}
+
+#[test]
+fn test_sccs_in_region() {
+    init_logger();
+    // A 4-cycle 00 -> 01 -> 11 -> 10 -> 00 with an extra 01 -> 00 transition.
+    let transitions = vec![
+        (0b00, 0b01),
+        (0b01, 0b11),
+        (0b11, 0b10),
+        (0b10, 0b00),
+        (0b01, 0b00),
+    ];
+    let bn = from_transitions(2, &transitions).expect("Failed to create network");
+    let graph = SymbolicAsyncGraph::new(&bn).expect("Failed to create graph");
+
+    let unit = graph.mk_unit_colored_vertices();
+    let found_sccs = sccs_in_region(&graph, &unit)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[&[0b00, 0b01, 0b10, 0b11]], 2);
+
+    // Without 10, the cycle is broken and only 00 <-> 01 remains.
+    let region = mk_states(&graph, &[0b00, 0b01, 0b11]);
+    let found_sccs = sccs_in_region(&graph, &region)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    verify_sccs(&graph, found_sccs, &[&[0b00, 0b01]], 2);
+
+    let region = mk_states(&graph, &[0b01, 0b11, 0b10]);
+    assert_eq!(sccs_in_region(&graph, &region).count(), 0);
+}
+
+#[test]
+fn test_nontrivial_colors() {
+    init_logger();
+    let graph = create_parameterized_test_network();
+
+    let single = mk_states(&graph, &[0b00]);
+    assert!(nontrivial_colors(&single).is_empty());
+    let pair = mk_states(&graph, &[0b00, 0b10]);
+    assert_eq!(nontrivial_colors(&pair), graph.mk_unit_colors());
+
+    // Every emitted SCC is already restricted to its non-trivial colors.
+    let sccs = FwdBwdScc::configure(&graph, &graph)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    assert!(!sccs.is_empty());
+    for scc in &sccs {
+        assert_eq!(nontrivial_colors(scc), scc.colors());
+    }
+    // 00 is in a non-trivial SCC only for p=false (for p=true, it is a fixed point).
+    let colors_of_00 = sccs
+        .iter()
+        .map(|it| it.intersect(&single).colors())
+        .fold(graph.mk_empty_colors(), |a, b| a.union(&b));
+    assert_eq!(colors_of_00.exact_cardinality(), BigUint::from(1u32));
+}
+
+/// Generic helper function for testing the `*WithProgress` variant `PROG` of the SCC
+/// algorithm `ALG`.
+fn test_scc_with_progress_impl<STATE, ALG, PROG>()
+where
+    ALG: SccAlgorithm<STATE>,
+    PROG: GenAlgorithm<SccConfig, STATE, SccItem> + 'static,
+    STATE: for<'a> From<&'a SymbolicAsyncGraph>,
+{
+    init_logger();
+    let graph = create_test_network();
+    let expected = ALG::configure(&graph, &graph)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+
+    // Without heartbeat, only the SCCs are reported.
+    let items = PROG::configure(&graph, &graph)
+        .map(|it| it.unwrap())
+        .collect::<Vec<_>>();
+    let sccs = items
+        .into_iter()
+        .map(|it| match it {
+            SccItem::Scc(scc) => scc,
+            SccItem::Progress(_) => panic!("Unexpected progress item."),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(sccs, expected);
+
+    // With heartbeat, the progress is reported in between and never increases.
+    let config = SccConfig::new(graph.clone()).heartbeat(true);
+    let mut sccs = Vec::new();
+    let mut progress = Vec::new();
+    for item in PROG::configure(config, &graph) {
+        match item.unwrap() {
+            SccItem::Scc(scc) => sccs.push(scc),
+            SccItem::Progress(it) => progress.push(it),
+        }
+    }
+    assert_eq!(sccs, expected);
+    assert!(!progress.is_empty());
+    assert!(
+        progress
+            .windows(2)
+            .all(|it| it[0].remaining_cardinality >= it[1].remaining_cardinality)
+    );
+}
+
+#[test]
+fn test_fwd_bwd_scc_with_progress() {
+    test_scc_with_progress_impl::<
+        FwdBwdState<ForwardReachability, BackwardReachability>,
+        FwdBwdScc,
+        FwdBwdSccWithProgress,
+    >();
+}
+
+#[test]
+fn test_fwd_bwd_scc_bfs_with_progress() {
+    test_scc_with_progress_impl::<
+        FwdBwdState<ForwardReachabilityBfs, BackwardReachabilityBfs>,
+        FwdBwdSccBfs,
+        FwdBwdSccBfsWithProgress,
+    >();
+}
+
+#[test]
+fn test_chain_scc_with_progress() {
+    test_scc_with_progress_impl::<ChainState, ChainScc, ChainSccWithProgress>();
+}
+
+#[test]
+fn test_skeleton_scc_with_progress() {
+    test_scc_with_progress_impl::<SkeletonState, SkeletonScc, SkeletonSccWithProgress>();
+}
@@ -85,10 +85,11 @@
 //! - To `110`: from `{110, 111, 100, 101, 011}` (attractor 2 plus its basin)
 //! - To `111`: from `{111, 110, 101, 011, 100}` (same as 110, since they form a cycle)
 
+use biodivine_lib_param_bn::BooleanNetwork;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
 
-use super::llm_transition_builder::from_transitions;
+use crate::build::from_transitions;
 
 /// Creates the canonical test network as a `SymbolicAsyncGraph`.
 ///
@@ -115,6 +116,32 @@ pub fn create_test_network() -> SymbolicAsyncGraph {
     SymbolicAsyncGraph::new(&bn).expect("Failed to create symbolic graph")
 }
 
+/// Creates a small parametrized network with two variables `A`, `B` and two colors, used to
+/// test the per-color behavior of the algorithms.
+///
+/// The network has one parameter `p` that switches between two behaviors:
+/// - When p=false: `A' = !A`, `B' = B`. Variable `A` flips in every state, giving the SCCs
+///   (and attractors) `{00, 10}` and `{01, 11}`.
+/// - When p=true: `A' = A ^ B`, `B' = A ^ B`. State `00` is a fixed point, and the remaining
+///   states form the SCC (and attractor) `{01, 10, 11}` (`01 → 11`, `10 → 11`,
+///   `11 → {01, 10}`).
+///
+/// States are encoded as binary `AB` (e.g., `0b10` means A=1, B=0).
+pub fn create_parameterized_test_network() -> SymbolicAsyncGraph {
+    // We use observable edges (-?) to avoid monotonicity constraints.
+    let aeon_model = r#"
+        A -? A
+        B -? A
+        A -? B
+        B -? B
+        $A: (p & (A ^ B)) | (!p & !A)
+        $B: (p & (A ^ B)) | (!p & B)
+    "#;
+
+    let bn = BooleanNetwork::try_from(aeon_model).expect("Failed to parse AEON model");
+    SymbolicAsyncGraph::new(&bn).expect("Failed to create symbolic graph")
+}
+
 /// State constants for readability in tests.
 pub mod states {
     /// State `000` - Fixed point, Attractor 1.
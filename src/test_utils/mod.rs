@@ -1,5 +1,4 @@
 pub mod llm_example_network;
-pub mod llm_transition_builder;
 
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
@@ -0,0 +1,28 @@
+use crate::reachability::ReachabilityConfig;
+use crate::trapping::ForwardTrap;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use cancel_this::{Cancellable, is_cancelled};
+use computation_process::Algorithm;
+
+/// Compute the [`ForwardTrap`] of the `initial` set, together with a witness explaining
+/// why the remaining states were removed.
+///
+/// The first set is the greatest forward trap contained in `initial`. The second set contains
+/// the states of `initial` which have a successor outside `initial` (i.e., states that escape
+/// the initial set directly, using one of the active variables). All other states removed from
+/// `initial` can reach such an escaping state. The witness is always a subset of
+/// `initial.minus(trap)`.
+pub fn forward_trap_with_witness<T: Into<ReachabilityConfig>>(
+    config: T,
+    initial: &GraphColoredVertices,
+) -> Cancellable<(GraphColoredVertices, GraphColoredVertices)> {
+    let config = config.into();
+    let mut escaping = config.graph.mk_empty_colored_vertices();
+    for var in &config.active_variables {
+        is_cancelled!()?;
+        escaping = escaping.union(&config.graph.var_can_post_out(*var, initial));
+    }
+    let trap = ForwardTrap::run(config, initial.clone())?;
+    Ok((trap, escaping))
+}
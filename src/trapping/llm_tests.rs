@@ -6,13 +6,18 @@
 //! A forward trap set is a set where all successors of states in the set are also in the set.
 //! A backward trap set is a set where all predecessors of states in the set are also in the set.
 
+use crate::reachability::ReachabilityConfig;
 use crate::test_utils::llm_example_network::create_test_network;
 use crate::test_utils::llm_example_network::sets::{
     ALL_STATES, ATTRACTOR_1, ATTRACTOR_2, SOURCE_STATES, STRONG_BASIN_ATTR1, WEAK_BASIN,
 };
 use crate::test_utils::llm_example_network::states::*;
 use crate::test_utils::{init_logger, mk_state, mk_states};
-use crate::trapping::{BackwardTrap, ForwardTrap};
+use crate::trapping::{
+    BackwardTrap, ForwardTrap, escaping_states_backward, escaping_states_forward,
+    forward_trap_with_witness, is_backward_trap, is_forward_trap, is_forward_trap_colors,
+    minimal_backward_trap, minimal_forward_trap,
+};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use cancel_this::Cancellable;
 use computation_process::Algorithm;
@@ -69,6 +74,54 @@ fn test_forward_trap_from_attractor_1() -> Cancellable<()> {
     Ok(())
 }
 
+#[test]
+fn test_forward_trap_from_singletons_are_fixed_points() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    // A singleton is its own forward trap if and only if it is a fixed point.
+    // In the example network, `000` is the only fixed point.
+    for state in ALL_STATES {
+        let initial = mk_state(&graph, *state);
+        let result = ForwardTrap::run(&graph, initial.clone())?;
+        if *state == S000 {
+            assert_eq!(
+                result, initial,
+                "Fixed point {:03b} is a forward trap",
+                state
+            );
+        } else {
+            assert!(
+                result.is_empty(),
+                "State {:03b} is not a forward trap",
+                state
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn test_forward_trap_with_witness() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    // 111 -> 110 escapes the set directly, while 011 is removed
+    // only because it can reach 111.
+    let initial = mk_states(&graph, &[S011, S001, S010, S000, S111]);
+    let (trap, witness) = forward_trap_with_witness(&graph, &initial)?;
+    assert_eq!(trap, mk_states(&graph, &[S001, S010, S000]));
+    assert_eq!(witness, mk_states(&graph, &[S111]));
+    assert!(witness.is_subset(&initial.minus(&trap)));
+
+    // Trap sets have no escaping states.
+    let attractor_2 = mk_states(&graph, ATTRACTOR_2);
+    let (trap, witness) = forward_trap_with_witness(&graph, &attractor_2)?;
+    assert_eq!(trap, attractor_2);
+    assert!(witness.is_empty());
+    Ok(())
+}
+
 #[test]
 fn test_forward_trap_from_attractor_2() -> Cancellable<()> {
     init_logger();
@@ -516,3 +569,108 @@ fn test_backward_trap_from_complete_basin() -> Cancellable<()> {
     );
     Ok(())
 }
+
+// ========== Predicate tests ==========
+
+#[test]
+fn test_backward_trap_within_universe() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let initial = mk_states(&graph, &[S000, S001]);
+
+    // Over the whole graph, 000 has predecessors 010 and 100, and 001 has predecessor 011.
+    let result = BackwardTrap::run(&graph, initial.clone())?;
+    assert!(result.is_empty());
+    let unit =
+        ReachabilityConfig::new(graph.clone()).with_universe(&graph.mk_unit_colored_vertices());
+    assert!(BackwardTrap::run(unit, initial.clone())?.is_empty());
+
+    // Within the universe, only predecessors inside it are considered.
+    let config = ReachabilityConfig::new(graph.clone()).with_universe(&initial);
+    assert_eq!(BackwardTrap::run(config, initial.clone())?, initial);
+
+    // 011 is in the universe, hence 001 (and then also 000) are removed.
+    let universe = mk_states(&graph, &[S000, S001, S011]);
+    let config = ReachabilityConfig::new(graph.clone()).with_universe(&universe);
+    assert!(BackwardTrap::run(config, initial)?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_minimal_traps() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let set = mk_state(&graph, S001);
+
+    let forward = minimal_forward_trap(&graph, &set)?;
+    assert_eq!(forward, mk_states(&graph, &[S000, S001]));
+    assert!(is_forward_trap(&graph, &forward));
+    // The greatest trap inside the minimal trap is the minimal trap itself.
+    assert_eq!(ForwardTrap::run(&graph, forward.clone())?, forward);
+
+    let backward = minimal_backward_trap(&graph, &set)?;
+    assert_eq!(backward, mk_states(&graph, &[S001, S011]));
+    assert!(is_backward_trap(&graph, &backward));
+    assert_eq!(BackwardTrap::run(&graph, backward.clone())?, backward);
+    Ok(())
+}
+
+#[test]
+fn test_trap_predicates() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+
+    assert!(is_forward_trap(&graph, &mk_states(&graph, ATTRACTOR_1)));
+    assert!(is_forward_trap(&graph, &mk_states(&graph, ATTRACTOR_2)));
+    assert!(!is_forward_trap(&graph, &mk_states(&graph, WEAK_BASIN)));
+    assert!(is_backward_trap(&graph, &mk_states(&graph, SOURCE_STATES)));
+    assert!(!is_backward_trap(&graph, &mk_states(&graph, ATTRACTOR_1)));
+
+    let colors = is_forward_trap_colors(&graph, &mk_states(&graph, ATTRACTOR_2));
+    assert_eq!(colors, graph.mk_unit_colors());
+    let colors = is_forward_trap_colors(&graph, &mk_states(&graph, WEAK_BASIN));
+    assert!(colors.is_empty());
+
+    // The results of trap computations always satisfy the predicates.
+    for state in ALL_STATES {
+        let initial = mk_states(&graph, &[*state, S110]);
+        assert!(is_forward_trap(
+            &graph,
+            &ForwardTrap::run(&graph, initial.clone())?
+        ));
+        assert!(is_backward_trap(
+            &graph,
+            &BackwardTrap::run(&graph, initial)?
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_escaping_states() {
+    init_logger();
+    let graph = create_test_network();
+    let unit = graph.mk_unit_colored_vertices();
+    let basin = mk_states(&graph, STRONG_BASIN_ATTR1);
+
+    // 001 -> 000 and 010 -> 000.
+    assert_eq!(escaping_states_forward(&graph, &basin, &unit), basin);
+    assert!(escaping_states_forward(&graph, &basin, &basin).is_empty());
+    // 011 -> 001, 011 -> 010 and 011 -> 111, but only 111 is in the universe.
+    let set = mk_state(&graph, S011);
+    assert_eq!(
+        escaping_states_forward(&graph, &set, &mk_state(&graph, S111)),
+        set
+    );
+    assert!(escaping_states_forward(&graph, &set, &mk_state(&graph, S000)).is_empty());
+
+    // 000 <- 001, 000 <- 010 and 000 <- 100.
+    let set = mk_state(&graph, S000);
+    assert_eq!(escaping_states_backward(&graph, &set, &unit), set);
+    assert_eq!(
+        escaping_states_backward(&graph, &set, &mk_state(&graph, S100)),
+        set
+    );
+    assert!(escaping_states_backward(&graph, &set, &mk_state(&graph, S011)).is_empty());
+    assert!(escaping_states_backward(&graph, &mk_states(&graph, SOURCE_STATES), &unit).is_empty());
+}
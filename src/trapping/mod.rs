@@ -1,18 +1,107 @@
+//! Computation of the greatest trap sets contained in a given initial set.
+//!
+//! A set `T` is a *forward trap* if every successor of every state in `T` is also in `T`
+//! (i.e., `post(T) ⊆ T`). Dually, `T` is a *backward trap* if `pre(T) ⊆ T`. The
+//! computations in this module return the greatest forward (backward) trap that is a subset
+//! of the initial set. This is done by iteratively removing states which have a successor
+//! (predecessor) outside the current set.
+//!
+//! In particular, a fixed point is always its own forward trap (it has no successors), and
+//! every forward trap contains at least one attractor (if it is non-empty). For parametrized
+//! networks, the property is evaluated independently for each color.
+//!
+//! Conversely, the smallest trap containing a given set is its reachability closure, which is
+//! available as [`minimal_forward_trap`] and [`minimal_backward_trap`].
+//!
+//! To analyze traps within an isolated region, the computations can be confined to
+//! a universe using [`ReachabilityConfig::with_universe`]. Successors (predecessors) outside
+//! of the universe are then ignored.
+//!
+//! The states that prevent a set from being a trap (within a universe) are given by
+//! [`escaping_states_forward`] and [`escaping_states_backward`]. These can be used to build
+//! custom trap refinement procedures.
+
+mod escape_witness;
+mod predicates;
 mod step_operators;
 
 #[cfg(test)]
 mod llm_tests;
 
-use crate::reachability::ReachabilityComputation;
+use crate::reachability::{
+    BackwardReachability, ForwardReachability, ReachabilityComputation, ReachabilityConfig,
+};
 use crate::trimming::IterativeSubtraction;
+use crate::{LogLabel, log_set};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use cancel_this::Cancellable;
+use computation_process::Algorithm;
+pub use escape_witness::forward_trap_with_witness;
+use log::info;
+pub use predicates::{
+    escaping_states_backward, escaping_states_forward, is_backward_trap, is_forward_trap,
+    is_forward_trap_colors,
+};
 pub use step_operators::{HasPredecessorSaturation, HasSuccessorSaturation};
 
 /// A type alias for a forward trap set computation (using saturation update).
 ///
-/// Forward trap set is the greatest forward-closed subset of the initial set.
+/// Forward trap set is the greatest forward-closed subset of the initial set
+/// (see the [module documentation](self) for the precise definition).
 pub type ForwardTrap = ReachabilityComputation<IterativeSubtraction<HasSuccessorSaturation>>;
 
 /// A type alias for a backward trap set computation (using saturation update).
 ///
-/// Backward trap set is the greatest backward-closed subset of the initial set.
+/// Backward trap set is the greatest backward-closed subset of the initial set
+/// (see the [module documentation](self) for the precise definition).
 pub type BackwardTrap = ReachabilityComputation<IterativeSubtraction<HasPredecessorSaturation>>;
+
+/// Compute the smallest forward trap containing `set`, i.e., the smallest forward-closed
+/// superset of `set`.
+///
+/// This is the dual of [`ForwardTrap`] (the greatest forward trap contained in a set) and
+/// it is exactly the forward reachable set of `set` (see [`ForwardReachability`]). For
+/// parametrized networks, the closure is computed for each color separately.
+pub fn minimal_forward_trap(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Cancellable<GraphColoredVertices> {
+    ForwardReachability::run(graph, set.clone())
+}
+
+/// Compute the smallest backward trap containing `set`, i.e., the smallest backward-closed
+/// superset of `set`.
+///
+/// This is the dual of [`BackwardTrap`] and it is exactly the backward reachable set of `set`
+/// (see [`BackwardReachability`]).
+pub fn minimal_backward_trap(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> Cancellable<GraphColoredVertices> {
+    BackwardReachability::run(graph, set.clone())
+}
+
+/// Replace `set` with its greatest forward trap (see [`ForwardTrap`]), logging the
+/// states that were removed. If `set` is already a forward trap, it is returned unchanged.
+pub(crate) fn restrict_to_forward_trap(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    label: &Option<String>,
+) -> Cancellable<GraphColoredVertices> {
+    let config = ReachabilityConfig {
+        label: label.clone(),
+        ..ReachabilityConfig::new(graph.clone())
+    };
+    let trap = ForwardTrap::run(config, set.clone())?;
+    let removed = set.minus(&trap);
+    if !removed.is_empty() {
+        info!(
+            "{}Initial set is not a forward trap. Removed ({}); remaining ({}).",
+            LogLabel(label),
+            log_set(&removed),
+            log_set(&trap),
+        );
+    }
+    Ok(trap)
+}
@@ -0,0 +1,58 @@
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
+
+/// Check that `set` is a forward trap, i.e., `post(set) ⊆ set`.
+///
+/// For parametrized networks, the property must hold for all colors.
+/// See also [`is_forward_trap_colors`].
+pub fn is_forward_trap(graph: &SymbolicAsyncGraph, set: &GraphColoredVertices) -> bool {
+    graph.post(set).is_subset(set)
+}
+
+/// Check that `set` is a backward trap, i.e., `pre(set) ⊆ set`.
+///
+/// For parametrized networks, the property must hold for all colors.
+pub fn is_backward_trap(graph: &SymbolicAsyncGraph, set: &GraphColoredVertices) -> bool {
+    graph.pre(set).is_subset(set)
+}
+
+/// Compute the colors for which `set` is a forward trap, i.e., the colors for which
+/// no transition leaves `set`.
+///
+/// Note that colors which do not appear in `set` are included in the result, since
+/// an empty set is trivially a trap.
+pub fn is_forward_trap_colors(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+) -> GraphColors {
+    let escaping = graph.post(set).minus(set);
+    graph.mk_unit_colors().minus(&escaping.colors())
+}
+
+/// Compute the states of `set` that have a successor outside of `set` but within `universe`.
+///
+/// This is the set of states that "leak" out of `set` when only the transitions inside
+/// `universe` are considered, i.e., `set` is a forward trap within `universe` (for a given color)
+/// if and only if the result is empty. States of `set` outside of `universe` are still
+/// considered, but only their transitions leading into `universe` are relevant. For
+/// parametrized networks, the result is evaluated for each color separately.
+pub fn escaping_states_forward(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    universe: &GraphColoredVertices,
+) -> GraphColoredVertices {
+    graph.restrict(&universe.union(set)).can_post_out(set)
+}
+
+/// Compute the states of `set` that have a predecessor outside of `set` but within `universe`.
+///
+/// See also [`escaping_states_forward`].
+pub fn escaping_states_backward(
+    graph: &SymbolicAsyncGraph,
+    set: &GraphColoredVertices,
+    universe: &GraphColoredVertices,
+) -> GraphColoredVertices {
+    graph.restrict(&universe.union(set)).can_pre_out(set)
+}
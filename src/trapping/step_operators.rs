@@ -1,5 +1,5 @@
-use crate::log_set;
 use crate::reachability::{ReachabilityConfig, ReachabilityStep};
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
 use cancel_this::{Cancellable, is_cancelled};
@@ -22,7 +22,11 @@ impl ReachabilityStep for HasSuccessorSaturation {
             is_cancelled!()?;
             let step = context.graph.var_can_post_out(*var, state);
             if !step.is_empty() {
-                trace!("[{var}] States with successors found ({}).", log_set(&step));
+                trace!(
+                    "{}[{var}] States with successors found ({}).",
+                    LogLabel(&context.label),
+                    log_set(&step)
+                );
                 return Ok(step);
             }
         }
@@ -41,7 +45,8 @@ impl ReachabilityStep for HasPredecessorSaturation {
             let step = context.graph.var_can_pre_out(*var, state);
             if !step.is_empty() {
                 trace!(
-                    "[{var}] States with predecessors found ({}).",
+                    "{}[{var}] States with predecessors found ({}).",
+                    LogLabel(&context.label),
                     log_set(&step)
                 );
                 return Ok(step);
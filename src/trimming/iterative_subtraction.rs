@@ -1,5 +1,5 @@
 use crate::reachability::{ReachabilityConfig, ReachabilityState, ReachabilityStep};
-use crate::{log_set, simple_type_name};
+use crate::{LogLabel, log_set, simple_type_name};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
 use cancel_this::Cancelled;
@@ -22,7 +22,8 @@ impl<S: ReachabilityStep>
     ) -> Completable<GraphColoredVertices> {
         if state.iteration >= context.max_iterations {
             debug!(
-                "[iteration:{}] Subtraction<{}> canceled (exceeded iteration count).",
+                "{}[iteration:{}] Subtraction<{}> canceled (exceeded iteration count).",
+                LogLabel(&context.label),
                 state.iteration,
                 simple_type_name::<S>()
             );
@@ -33,9 +34,11 @@ impl<S: ReachabilityStep>
         }
 
         let to_remove = S::step(context, &state.set)?;
+        context.observe_peak(&to_remove);
         if to_remove.is_empty() {
             debug!(
-                "[iteration:{}] Subtraction<{}> finished with ({}).",
+                "{}[iteration:{}] Subtraction<{}> finished with ({}).",
+                LogLabel(&context.label),
                 state.iteration,
                 simple_type_name::<S>(),
                 log_set(&state.set)
@@ -44,10 +47,12 @@ impl<S: ReachabilityStep>
             Ok(state.set.clone())
         } else {
             state.set = state.set.minus(&to_remove);
+            context.observe_peak(&state.set);
 
             if state.set.symbolic_size() > context.max_symbolic_size {
                 debug!(
-                    "[iteration:{}] Subtraction<{}> canceled (exceeded symbolic size).",
+                    "{}[iteration:{}] Subtraction<{}> canceled (exceeded symbolic size).",
+                    LogLabel(&context.label),
                     state.iteration,
                     simple_type_name::<S>()
                 );
@@ -56,12 +61,17 @@ impl<S: ReachabilityStep>
             }
 
             debug!(
-                "[iteration:{}] Subtraction<{}> decreased to ({}).",
+                "{}[iteration:{}] Subtraction<{}> decreased to ({}).",
+                LogLabel(&context.label),
                 state.iteration,
                 simple_type_name::<S>(),
                 log_set(&state.set)
             );
 
+            if let Some(progress) = &context.progress {
+                progress.notify(state.iteration, &state.set);
+            }
+
             Err(Suspended)
         }
     }
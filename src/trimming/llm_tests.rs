@@ -6,16 +6,23 @@
 //! from the initial set. A source state has no predecessors within the remaining states
 //! (complement), and a sink state has no successors within the remaining states.
 
+use crate::build::from_transitions;
+use crate::reachability::{ReachabilityConfig, ReachabilityState, ReachabilityStep};
 use crate::test_utils::llm_example_network::create_test_network;
 use crate::test_utils::llm_example_network::sets::{
     ALL_STATES, ATTRACTOR_1, ATTRACTOR_2, SOURCE_STATES, STRONG_BASIN_ATTR1, WEAK_BASIN,
 };
 use crate::test_utils::llm_example_network::states::*;
-use crate::test_utils::{init_logger, mk_state, mk_states};
-use crate::trimming::{TrimSinks, TrimSources};
+use crate::test_utils::{collect_state_numbers, init_logger, mk_state, mk_states};
+use crate::trimming::{
+    IterativeSubtraction, RelativeSinks, RelativeSinksAndSources, RelativeSources, TrimSinks,
+    TrimSinksAndSources, TrimSources, sink_states, source_states, trim_layers,
+};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
 use cancel_this::Cancellable;
-use computation_process::Algorithm;
+use computation_process::Incomplete::Suspended;
+use computation_process::{Algorithm, ComputationStep};
 
 // ========== TrimSources tests ==========
 
@@ -453,3 +460,113 @@ fn test_trim_sinks_is_subset_of_initial() -> Cancellable<()> {
     }
     Ok(())
 }
+
+// ========== Layer-by-layer trimming ==========
+
+/// Run the trimming step by step and collect the state numbers removed in each step.
+fn trimming_layers<S: ReachabilityStep>(
+    graph: &SymbolicAsyncGraph,
+    initial: &[u32],
+) -> Cancellable<Vec<Vec<u32>>> {
+    let config = ReachabilityConfig::from(graph);
+    let mut state = ReachabilityState::from(mk_states(graph, initial));
+    let mut layers = Vec::new();
+    loop {
+        let layer = S::step(&config, &state.set)?;
+        let before = state.set.clone();
+        match IterativeSubtraction::<S>::step(&config, &mut state) {
+            Ok(result) => {
+                assert!(layer.is_empty());
+                assert_eq!(result, before);
+                return Ok(layers);
+            }
+            Err(Suspended) => {
+                // Exactly one layer was removed in this step.
+                assert_eq!(state.set, before.minus(&layer));
+                layers.push(collect_state_numbers(graph, &layer, 3));
+            }
+            Err(_) => panic!("Unexpected cancellation."),
+        }
+    }
+}
+
+#[test]
+fn test_trimming_removes_one_layer_per_step() -> Cancellable<()> {
+    init_logger();
+    // A simple chain 000 -> 001 -> 011 -> 111.
+    let bn = from_transitions(3, &[(0b000, 0b001), (0b001, 0b011), (0b011, 0b111)]).unwrap();
+    let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+    let chain = [0b000, 0b001, 0b011, 0b111];
+
+    assert_eq!(
+        trimming_layers::<RelativeSinks>(&graph, &chain)?,
+        vec![vec![0b111], vec![0b011], vec![0b001], vec![0b000]]
+    );
+    assert_eq!(
+        trimming_layers::<RelativeSources>(&graph, &chain)?,
+        vec![vec![0b000], vec![0b001], vec![0b011], vec![0b111]]
+    );
+    assert_eq!(
+        trimming_layers::<RelativeSinksAndSources>(&graph, &chain)?,
+        vec![vec![0b000], vec![0b001], vec![0b011], vec![0b111]]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_relative_sources_include_absolute_sources() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let config = ReachabilityConfig::new(graph.clone());
+
+    // Within the whole state space, relative sources are exactly the absolute sources.
+    let unit = graph.mk_unit_colored_vertices();
+    let sources = RelativeSources::step(&config, &unit)?;
+    assert_eq!(sources, mk_states(&graph, SOURCE_STATES));
+
+    // In any subset, the absolute sources are part of the first layer.
+    let set = mk_states(&graph, &[S000, S001, S011, S100]);
+    let sources = RelativeSources::step(&config, &set)?;
+    assert_eq!(sources, mk_states(&graph, &[S011, S100]));
+    Ok(())
+}
+
+#[test]
+fn test_trim_layers() -> Cancellable<()> {
+    init_logger();
+    let graph = create_test_network();
+    let unit = graph.mk_unit_colored_vertices();
+    let (core, layers) = trim_layers::<RelativeSinksAndSources>(&graph, unit.clone())?;
+    assert_eq!(core, TrimSinksAndSources::run(&graph, unit.clone())?);
+    assert_eq!(core, mk_states(&graph, ATTRACTOR_2));
+
+    // Sources {011, 100, 101} first, then the exposed sources {001, 010}, then the sink 000.
+    let layers = layers
+        .iter()
+        .map(|it| collect_state_numbers(&graph, it, 3))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        layers,
+        vec![vec![S011, S100, S101], vec![S001, S010], vec![S000]]
+    );
+
+    // A set without trivial states has no layers.
+    let (core, layers) = trim_layers::<RelativeSinks>(&graph, core)?;
+    assert_eq!(core, mk_states(&graph, ATTRACTOR_2));
+    assert!(layers.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_source_and_sink_states() {
+    init_logger();
+    let graph = create_test_network();
+    assert_eq!(source_states(&graph), mk_states(&graph, SOURCE_STATES));
+    assert_eq!(sink_states(&graph), mk_states(&graph, ATTRACTOR_1));
+
+    // A cycle has neither sources nor sinks: 00 -> 01 -> 11 -> 10 -> 00.
+    let bn = from_transitions(2, &[(0, 1), (1, 3), (3, 2), (2, 0)]).unwrap();
+    let graph = SymbolicAsyncGraph::new(&bn).unwrap();
+    assert!(source_states(&graph).is_empty());
+    assert!(sink_states(&graph).is_empty());
+}
@@ -6,7 +6,10 @@
 //! - **Sources**: States with no predecessors within the remaining set
 //!
 //! Trimming is applied iteratively until a fixed point is reached, as removing
-//! one layer of sinks/sources may expose new ones.
+//! one layer of sinks/sources may expose new ones. Each step of a trimming computation
+//! (i.e., each suspension of [`Computable::try_compute`]) removes exactly one such layer,
+//! and the removed layer is the result of the underlying step operator ([`RelativeSinks`],
+//! [`RelativeSources`], or [`RelativeSinksAndSources`]).
 //!
 //! # Algorithms
 //!
@@ -14,6 +17,9 @@
 //! - [`TrimSources`]: Iteratively remove source states
 //! - [`TrimSinksAndSources`]: Remove both (more efficient than separate passes)
 //!
+//! To also learn in which iteration each state was removed, use [`trim_layers`]. The absolute
+//! sources and sinks of the whole graph are given by [`source_states`] and [`sink_states`].
+//!
 //! # Configuration
 //!
 //! Use [`TrimSetting`] to select which trimming strategy to apply:
@@ -29,12 +35,17 @@ mod step_operators;
 #[cfg(test)]
 mod llm_tests;
 
-use crate::reachability::ReachabilityComputation;
-use crate::trimming::step_operators::RelativeSinksAndSources;
+use crate::reachability::{
+    ReachabilityComputation, ReachabilityConfig, ReachabilityState, ReachabilityStep,
+};
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
-use computation_process::{Completable, Computable, ComputableIdentity, Stateful};
+use cancel_this::{Cancellable, Cancelled};
+use computation_process::{
+    Completable, Computable, ComputableIdentity, ComputationStep, Incomplete, Stateful,
+};
 pub use iterative_subtraction::IterativeSubtraction;
-pub use step_operators::{RelativeSinks, RelativeSources};
+pub use step_operators::{RelativeSinks, RelativeSinksAndSources, RelativeSources};
 
 /// Trimming algorithm that iteratively removes sink states.
 pub type TrimSinks = ReachabilityComputation<IterativeSubtraction<RelativeSinks>>;
@@ -46,6 +57,52 @@ pub type TrimSources = ReachabilityComputation<IterativeSubtraction<RelativeSour
 pub type TrimSinksAndSources =
     ReachabilityComputation<IterativeSubtraction<RelativeSinksAndSources>>;
 
+/// Compute the source states of the `graph`, i.e., the states without any predecessors
+/// ("garden of Eden" states).
+///
+/// Unlike [`RelativeSources`], this is a one-shot computation in the whole graph. For
+/// parametrized networks, a state-color pair is in the result if the state has no predecessors
+/// using the transitions of that color.
+pub fn source_states(graph: &SymbolicAsyncGraph) -> GraphColoredVertices {
+    let unit = graph.mk_unit_colored_vertices();
+    unit.minus(&graph.can_pre(&unit))
+}
+
+/// Compute the sink states of the `graph`, i.e., the states without any successors
+/// (the fixed points).
+///
+/// See also [`source_states`].
+pub fn sink_states(graph: &SymbolicAsyncGraph) -> GraphColoredVertices {
+    let unit = graph.mk_unit_colored_vertices();
+    unit.minus(&graph.can_post(&unit))
+}
+
+/// Trim `set` using the step operator `S` (e.g., [`RelativeSinksAndSources`] to match
+/// [`TrimSinksAndSources`]), recording the states removed in each iteration.
+///
+/// The result is the trimmed core (the same as the result of the corresponding trimming
+/// computation) together with the removed layers: `layers[i]` contains the states removed in
+/// iteration `i`, i.e., the "depth" of these states in the trivial tail of the set. The layers
+/// are pairwise disjoint, and together with the core, they cover the whole `set`.
+pub fn trim_layers<S: ReachabilityStep>(
+    config: impl Into<ReachabilityConfig>,
+    set: GraphColoredVertices,
+) -> Cancellable<(GraphColoredVertices, Vec<GraphColoredVertices>)> {
+    let config = config.into();
+    let mut state = ReachabilityState::from(set);
+    let mut layers = Vec::new();
+    loop {
+        let before = state.set.clone();
+        match IterativeSubtraction::<S>::step(&config, &mut state) {
+            Ok(core) => return Ok((core, layers)),
+            Err(Incomplete::Suspended) => layers.push(before.minus(&state.set)),
+            Err(Incomplete::Cancelled(cancelled)) => return Err(cancelled),
+            // The steps do not produce other variants, but `Incomplete` is non-exhaustive.
+            Err(_) => return Err(Cancelled::new("trim_layers")),
+        }
+    }
+}
+
 /// Configuration for trimming behavior during SCC computation.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -61,6 +118,9 @@ pub enum TrimSetting {
     None,
 }
 
+/// A trimming computation selected at runtime by [`TrimSetting::build_computation`].
+///
+/// Each variant holds the concrete (statically typed) computation.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TrimComputation {
     None(ComputableIdentity<GraphColoredVertices>),
@@ -82,15 +142,24 @@ impl Computable<GraphColoredVertices> for TrimComputation {
 
 impl TrimSetting {
     /// Build a trimming computation based on the current setting.
+    ///
+    /// The `config` is typically just a
+    /// [`SymbolicAsyncGraph`](biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph),
+    /// but a full [`ReachabilityConfig`] can be used as well.
+    ///
+    /// The result is a [`TrimComputation`] enum, so no allocation or dynamic dispatch is
+    /// involved. If the setting is known statically, the concrete computation can be created
+    /// directly instead (e.g., `TrimSinksAndSources::configure(config, set)` using
+    /// [`Stateful::configure`]).
     pub fn build_computation(
         &self,
-        graph: &SymbolicAsyncGraph,
+        config: impl Into<ReachabilityConfig>,
         set: GraphColoredVertices,
     ) -> TrimComputation {
         match self {
-            TrimSetting::Both => TrimComputation::Both(TrimSinksAndSources::configure(graph, set)),
-            TrimSetting::Sources => TrimComputation::Sources(TrimSources::configure(graph, set)),
-            TrimSetting::Sinks => TrimComputation::Sinks(TrimSinks::configure(graph, set)),
+            TrimSetting::Both => TrimComputation::Both(TrimSinksAndSources::configure(config, set)),
+            TrimSetting::Sources => TrimComputation::Sources(TrimSources::configure(config, set)),
+            TrimSetting::Sinks => TrimComputation::Sinks(TrimSinks::configure(config, set)),
             TrimSetting::None => TrimComputation::None(ComputableIdentity::from(set)),
         }
     }
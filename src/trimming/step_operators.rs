@@ -1,5 +1,5 @@
-use crate::log_set;
 use crate::reachability::{ReachabilityConfig, ReachabilityStep};
+use crate::{LogLabel, log_set};
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
 use cancel_this::{Cancellable, is_cancelled};
@@ -7,14 +7,28 @@ use log::trace;
 
 /// Identifies states that are "sinks" within the given set. These are states that do not
 /// have a successor within the given set.
+///
+/// One step removes one layer of relative sinks: the returned set contains exactly
+/// the current sinks (not the sinks that only appear once these are removed).
 pub struct RelativeSinks;
 
 /// Identifies states that are "sources" within the given set. These are states that do not
 /// have a predecessor within the given set.
+///
+/// One step removes one layer of relative sources: the returned set contains exactly
+/// the current sources (not the sources that only appear once these are removed).
+///
+/// Note that every absolute source (a state without predecessors in the whole graph) is also
+/// a relative source, so all absolute sources are removed by the first step. Detecting them
+/// requires the same per-variable predecessor checks as detecting relative sources, hence
+/// computing them separately beforehand would not save any symbolic operations.
 pub struct RelativeSources;
 
 /// The union of [`RelativeSinks`] and [`RelativeSources`] which allows us to trim a set
 /// from "both sides".
+///
+/// One step removes one layer of relative sources or, if there are no sources, one layer
+/// of relative sinks.
 pub struct RelativeSinksAndSources;
 
 impl ReachabilityStep for RelativeSinks {
@@ -31,7 +45,8 @@ impl ReachabilityStep for RelativeSinks {
             if !var_successor.is_subset(&has_successor) {
                 has_successor = has_successor.union(&var_successor);
                 trace!(
-                    "Inverted sinks updated using `{var}` ({}).",
+                    "{}Inverted sinks updated using `{var}` ({}).",
+                    LogLabel(&context.label),
                     log_set(&has_successor)
                 );
             }
@@ -55,7 +70,8 @@ impl ReachabilityStep for RelativeSources {
             if !var_predecessor.is_subset(&has_predecessor) {
                 has_predecessor = has_predecessor.union(&var_predecessor);
                 trace!(
-                    "Inverted sources updated using `{var}` ({}).",
+                    "{}Inverted sources updated using `{var}` ({}).",
+                    LogLabel(&context.label),
                     log_set(&has_predecessor)
                 );
             }